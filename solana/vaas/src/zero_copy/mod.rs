@@ -11,6 +11,7 @@ pub use posted_vaa_v1::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::account_info::AccountInfo;
 use wormhole_raw_vaas::Payload;
+use wormhole_vaas::MessageId;
 
 #[cfg(feature = "anchor")]
 type FeatureResult<T> = anchor_lang::Result<T>;
@@ -37,6 +38,18 @@ pub struct EmitterInfo {
     pub sequence: u64,
 }
 
+impl From<EmitterInfo> for MessageId {
+    fn from(info: EmitterInfo) -> Self {
+        MessageId::new(info.chain.into(), info.address, info.sequence)
+    }
+}
+
+impl From<&VaaAccount<'_>> for MessageId {
+    fn from(acc: &VaaAccount<'_>) -> Self {
+        acc.message_id()
+    }
+}
+
 impl<'a> VaaAccount<'a> {
     pub fn version(&'a self) -> u8 {
         match self {
@@ -64,6 +77,10 @@ impl<'a> VaaAccount<'a> {
         }
     }
 
+    pub fn message_id(&self) -> MessageId {
+        self.emitter_info().into()
+    }
+
     pub fn emitter_chain(&self) -> u16 {
         match self {
             Self::PostedVaaV1(inner) => inner.emitter_chain(),
@@ -0,0 +1,635 @@
+//! Generates the chain list, deployment, chain info, EVM chain id and CCTP tables of
+//! `wormhole-deploys` from `deploys/data/deployments.json`.
+//!
+//! After editing the data file, run `cargo run -p wormhole-deploys-generate` to rewrite
+//! `deploys/src/generated`. With `--check`, the generated files are only compared against the
+//! checked-in ones.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use serde::Deserialize;
+use wormhole_deploys::{NetEnv, Vm};
+
+const HEADER: &str =
+    "// @generated by wormhole-deploys-generate from data/deployments.json. Do not edit.\n";
+
+/// The `nets_group!` of each VM: heading, module, constant and address decoder.
+const GROUPS: &[(Vm, &str, &str, &str, &str)] = &[
+    (Vm::Evm, "EVM", "evm", "EVM_NETS", "hex"),
+    (Vm::Solana, "Solana", "solana", "SOLANA_NETS", "b58_32"),
+    (
+        Vm::CosmWasm,
+        "CosmWasm",
+        "cosm_wasm",
+        "COSM_WASM_NETS",
+        "bech32",
+    ),
+    (Vm::Sui, "Sui", "sui", "SUI_NETS", "hex_0x"),
+    (Vm::Aptos, "Aptos", "aptos", "APTOS_NETS", "hex_0x"),
+    (Vm::Near, "Near", "near", "NEAR_NETS", "utf8"),
+    (
+        Vm::Algorand,
+        "Algorand",
+        "algorand",
+        "ALGORAND_NETS",
+        "app_id",
+    ),
+];
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Data {
+    chains: Vec<Chain>,
+    deployments: Vec<Deployment>,
+    chain_infos: Vec<ChainInfo>,
+    evm_chains: Vec<EvmChain>,
+    cctp: Vec<Cctp>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Chain {
+    /// The `KnownChainId` variant.
+    name: String,
+    id: u16,
+    /// Set to `false` for chains which are known but not (yet) deployed to.
+    #[serde(default = "yes")]
+    deployed: bool,
+}
+
+fn yes() -> bool {
+    true
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Deployment {
+    /// The name of the generated constant.
+    ident: String,
+    /// The `KnownChainId` variant.
+    chain: String,
+    name: String,
+    vm: Vm,
+    net_env: NetEnv,
+    #[serde(default)]
+    aliases: Vec<String>,
+    core: String,
+    token_bridge: Option<String>,
+    nft_bridge: Option<String>,
+    token_router: Option<String>,
+    standard_relayer: Option<String>,
+    matching_engine: Option<String>,
+    queries_verifier: Option<String>,
+    global_accountant: Option<String>,
+    post_message_shim: Option<String>,
+    verify_vaa_shim: Option<String>,
+}
+
+impl Deployment {
+    fn addresses(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        [
+            ("core", Some(&self.core)),
+            ("token_bridge", self.token_bridge.as_ref()),
+            ("nft_bridge", self.nft_bridge.as_ref()),
+            ("token_router", self.token_router.as_ref()),
+            ("standard_relayer", self.standard_relayer.as_ref()),
+            ("matching_engine", self.matching_engine.as_ref()),
+            ("queries_verifier", self.queries_verifier.as_ref()),
+            ("global_accountant", self.global_accountant.as_ref()),
+            ("post_message_shim", self.post_message_shim.as_ref()),
+            ("verify_vaa_shim", self.verify_vaa_shim.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(field, address)| Some((field, address?.as_str())))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChainInfo {
+    /// The `KnownChainId` variant.
+    chain: String,
+    /// The default and the finalized consistency level.
+    consistency_levels: (u8, u8),
+    block_time_ms: u64,
+    confirmations: u32,
+    governor: Option<GovernorLimits>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GovernorLimits {
+    daily_limit: u64,
+    big_transaction_size: Option<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EvmChain {
+    /// The `KnownChainId` variant.
+    chain: String,
+    net_env: NetEnv,
+    evm_chain_id: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Cctp {
+    /// The name of the generated constant.
+    ident: String,
+    /// The `KnownChainId` variant.
+    chain: String,
+    net_env: NetEnv,
+    domain: u32,
+    circle_integration: Option<String>,
+    token_messenger: String,
+    message_transmitter: String,
+    usdc: String,
+}
+
+impl Cctp {
+    fn addresses(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        [
+            ("circle_integration", self.circle_integration.as_ref()),
+            ("token_messenger", Some(&self.token_messenger)),
+            ("message_transmitter", Some(&self.message_transmitter)),
+            ("usdc", Some(&self.usdc)),
+        ]
+        .into_iter()
+        .filter_map(|(field, address)| Some((field, address?.as_str())))
+    }
+}
+
+/// The contents of the generated files.
+#[derive(Debug, PartialEq, Eq)]
+struct Generated {
+    known_chains: String,
+    deployments: String,
+    chain_infos: String,
+    evm_chains: String,
+    cctp: String,
+}
+
+/// Check the data for consistency, returning every problem found.
+fn check(data: &Data) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    let mut chain_names = BTreeSet::new();
+    let mut chain_ids = BTreeSet::new();
+    for chain in &data.chains {
+        if !chain_names.insert(chain.name.as_str()) {
+            errors.push(format!("duplicate chain {}", chain.name));
+        }
+        if !chain_ids.insert(chain.id) {
+            errors.push(format!("duplicate chain id {}", chain.id));
+        }
+    }
+
+    let mut idents = BTreeSet::new();
+    let mut by_chain = BTreeSet::new();
+    let mut names = BTreeMap::new();
+    for deployment in &data.deployments {
+        let ident = &deployment.ident;
+        if !idents.insert(ident.as_str()) {
+            errors.push(format!("duplicate deployment {ident}"));
+        }
+        if !chain_names.contains(deployment.chain.as_str()) {
+            errors.push(format!("{ident}: unknown chain {}", deployment.chain));
+        }
+        if !GROUPS.iter().any(|(vm, ..)| *vm == deployment.vm) {
+            errors.push(format!(
+                "{ident}: no deployments table for {:?}",
+                deployment.vm
+            ));
+        }
+        if !by_chain.insert((deployment.chain.as_str(), deployment.net_env as u8)) {
+            errors.push(format!(
+                "{ident}: more than one {:?} deployment of {}",
+                deployment.net_env, deployment.chain
+            ));
+        }
+
+        // The by-name maps are case-insensitive and shared by names and aliases.
+        for name in std::iter::once(&deployment.name).chain(&deployment.aliases) {
+            if name.is_empty() {
+                errors.push(format!("{ident}: empty name or alias"));
+                continue;
+            }
+            let key = (deployment.net_env as u8, name.to_ascii_lowercase());
+            if let Some(other) = names.insert(key, ident) {
+                errors.push(format!(
+                    "{ident}: name or alias {name:?} is taken by {other}"
+                ));
+            }
+        }
+
+        for (field, address) in deployment.addresses() {
+            if let Err(e) = deployment.vm.parse_address(address) {
+                errors.push(format!("{ident}: {field} {address:?}: {e}"));
+            }
+            // `hex_0x` decodes the literal as is, so leading zeros must not be omitted.
+            if matches!(deployment.vm, Vm::Sui | Vm::Aptos) && address.len() != 66 {
+                errors.push(format!(
+                    "{ident}: {field} {address:?} must be written out in full"
+                ));
+            }
+        }
+    }
+
+    let mut infos = BTreeSet::new();
+    for info in &data.chain_infos {
+        if !chain_names.contains(info.chain.as_str()) {
+            errors.push(format!("chain info: unknown chain {}", info.chain));
+        }
+        if !infos.insert(info.chain.as_str()) {
+            errors.push(format!("chain info: duplicate chain {}", info.chain));
+        }
+    }
+
+    let mut evm_chains = BTreeSet::new();
+    let mut evm_chain_ids = BTreeSet::new();
+    for evm_chain in &data.evm_chains {
+        let EvmChain {
+            chain,
+            net_env,
+            evm_chain_id,
+        } = evm_chain;
+        if !chain_names.contains(chain.as_str()) {
+            errors.push(format!(
+                "EVM chain id {evm_chain_id}: unknown chain {chain}"
+            ));
+        }
+        if !evm_chains.insert((chain.as_str(), *net_env as u8)) {
+            errors.push(format!(
+                "more than one {net_env:?} EVM chain id for {chain}"
+            ));
+        }
+        if !evm_chain_ids.insert((*net_env as u8, *evm_chain_id)) {
+            errors.push(format!("duplicate {net_env:?} EVM chain id {evm_chain_id}"));
+        }
+    }
+
+    let mut cctp_idents = BTreeSet::new();
+    let mut cctp_chains = BTreeSet::new();
+    let mut cctp_domains = BTreeSet::new();
+    for cctp in &data.cctp {
+        let ident = format!("CCTP {}", cctp.ident);
+        if !cctp_idents.insert(cctp.ident.as_str()) {
+            errors.push(format!("duplicate {ident}"));
+        }
+        if !cctp_chains.insert((cctp.chain.as_str(), cctp.net_env as u8)) {
+            errors.push(format!(
+                "{ident}: more than one {:?} CCTP deployment of {}",
+                cctp.net_env, cctp.chain
+            ));
+        }
+        if !cctp_domains.insert((cctp.net_env as u8, cctp.domain)) {
+            errors.push(format!(
+                "{ident}: duplicate {:?} domain {}",
+                cctp.net_env, cctp.domain
+            ));
+        }
+        // The addresses are written for the VM of the chain's Wormhole deployment.
+        let Some(vm) = cctp_vm(data, cctp) else {
+            errors.push(format!(
+                "{ident}: no {:?} deployment of {}",
+                cctp.net_env, cctp.chain
+            ));
+            continue;
+        };
+        if !matches!(vm, Vm::Evm | Vm::Solana) {
+            errors.push(format!("{ident}: CCTP is not supported on {vm:?}"));
+            continue;
+        }
+        for (field, address) in cctp.addresses() {
+            if let Err(e) = vm.parse_address(address) {
+                errors.push(format!("{ident}: {field} {address:?}: {e}"));
+            }
+        }
+    }
+
+    for chain in &data.chains {
+        let deployed = data.deployments.iter().any(|d| d.chain == chain.name);
+        match (chain.deployed, deployed) {
+            (true, false) => errors.push(format!(
+                "{} has no deployments, mark it with `\"deployed\": false`",
+                chain.name
+            )),
+            (false, true) => errors.push(format!(
+                "{} has deployments but is marked with `\"deployed\": false`",
+                chain.name
+            )),
+            _ => {}
+        }
+    }
+
+    errors
+}
+
+fn generate(data: &Data) -> Generated {
+    let mut known_chains = format!("{HEADER}\nknown_chains! {{\n");
+    for chain in &data.chains {
+        writeln!(known_chains, "    {} = {},", chain.name, chain.id).unwrap();
+    }
+    known_chains.push_str("}\n");
+
+    let mut deployments = String::from(HEADER);
+    for (vm, heading, module, constant, decoder) in GROUPS {
+        writeln!(deployments, "\n// {heading}").unwrap();
+        writeln!(
+            deployments,
+            "nets_group! {{{module}, {vm:?}, {constant}, {decoder};"
+        )
+        .unwrap();
+        let mut net_env = None;
+        for deployment in data.deployments.iter().filter(|d| d.vm == *vm) {
+            if net_env != Some(deployment.net_env) {
+                if net_env.is_some() {
+                    deployments.push('\n');
+                }
+                writeln!(deployments, "    // {:?}s\n", deployment.net_env).unwrap();
+                net_env = Some(deployment.net_env);
+            }
+            write_deployment(&mut deployments, deployment);
+        }
+        deployments.push_str("}\n");
+    }
+
+    let mut chain_infos = format!("{HEADER}\nchain_infos! {{\n");
+    for info in &data.chain_infos {
+        let (default, finalized) = info.consistency_levels;
+        writeln!(chain_infos, "    {} {{", info.chain).unwrap();
+        writeln!(
+            chain_infos,
+            "        consistency_levels: ({default}, {finalized}),"
+        )
+        .unwrap();
+        writeln!(
+            chain_infos,
+            "        block_time_ms: {},",
+            info.block_time_ms
+        )
+        .unwrap();
+        writeln!(
+            chain_infos,
+            "        confirmations: {},",
+            info.confirmations
+        )
+        .unwrap();
+        if let Some(governor) = &info.governor {
+            writeln!(
+                chain_infos,
+                "        governor: ({}, {:?}),",
+                governor.daily_limit, governor.big_transaction_size
+            )
+            .unwrap();
+        }
+        writeln!(chain_infos, "    }},").unwrap();
+    }
+    chain_infos.push_str("}\n");
+
+    let mut evm_chains = format!("{HEADER}\nevm_chains! {{\n");
+    let mut net_env = None;
+    for evm_chain in &data.evm_chains {
+        if net_env != Some(evm_chain.net_env) {
+            if net_env.is_some() {
+                evm_chains.push_str("    }\n");
+            }
+            writeln!(evm_chains, "    {:?} {{", evm_chain.net_env).unwrap();
+            net_env = Some(evm_chain.net_env);
+        }
+        writeln!(
+            evm_chains,
+            "        {} = {},",
+            evm_chain.chain, evm_chain.evm_chain_id
+        )
+        .unwrap();
+    }
+    if net_env.is_some() {
+        evm_chains.push_str("    }\n");
+    }
+    evm_chains.push_str("}\n");
+
+    let mut cctp = format!("{HEADER}\ncctp_group! {{\n");
+    let mut net_env = None;
+    for deployment in &data.cctp {
+        if net_env != Some(deployment.net_env) {
+            if net_env.is_some() {
+                cctp.push('\n');
+            }
+            writeln!(cctp, "    // {:?}s\n", deployment.net_env).unwrap();
+            net_env = Some(deployment.net_env);
+        }
+        write_cctp(&mut cctp, data, deployment);
+    }
+    cctp.push_str("}\n");
+
+    Generated {
+        known_chains,
+        deployments,
+        chain_infos,
+        evm_chains,
+        cctp,
+    }
+}
+
+/// The VM of the Wormhole deployment on the chain of `cctp`.
+fn cctp_vm(data: &Data, cctp: &Cctp) -> Option<Vm> {
+    data.deployments
+        .iter()
+        .find(|d| d.chain == cctp.chain && d.net_env == cctp.net_env)
+        .map(|d| d.vm)
+}
+
+fn write_cctp(out: &mut String, data: &Data, cctp: &Cctp) {
+    let vm = cctp_vm(data, cctp).unwrap();
+    let (.., decoder) = GROUPS.iter().find(|(group, ..)| *group == vm).unwrap();
+
+    writeln!(out, "    {} {{", cctp.ident).unwrap();
+    writeln!(out, "        chain_id: {},", cctp.chain).unwrap();
+    writeln!(out, "        domain: {},", cctp.domain).unwrap();
+    for (field, address) in cctp.addresses() {
+        let address = match vm {
+            Vm::Evm => address.trim_start_matches("0x"),
+            _ => address,
+        };
+        writeln!(out, "        {field}: {decoder}!({address:?}),").unwrap();
+    }
+    writeln!(out, "        net_env: {:?},", cctp.net_env).unwrap();
+    writeln!(out, "    }},").unwrap();
+}
+
+fn write_deployment(out: &mut String, deployment: &Deployment) {
+    let aliases = deployment
+        .aliases
+        .iter()
+        .map(|alias| format!("{alias:?}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    writeln!(out, "    {} {{", deployment.ident).unwrap();
+    writeln!(out, "        chain_id: {},", deployment.chain).unwrap();
+    writeln!(out, "        name: {:?},", deployment.name).unwrap();
+    writeln!(out, "        aliases: [{aliases}],").unwrap();
+    for (field, address) in deployment.addresses() {
+        // The `hex` decoder of EVM addresses takes them without the `0x` prefix.
+        let address = match deployment.vm {
+            Vm::Evm => address.trim_start_matches("0x"),
+            _ => address,
+        };
+        writeln!(out, "        {field}: {address:?},").unwrap();
+    }
+    writeln!(out, "        net_env: {:?},", deployment.net_env).unwrap();
+    writeln!(out, "    }},").unwrap();
+}
+
+fn deploys_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn load() -> Result<Generated, Vec<String>> {
+    let path = deploys_dir().join("data/deployments.json");
+    let json = fs::read_to_string(&path).map_err(|e| vec![format!("{}: {e}", path.display())])?;
+    let data: Data =
+        serde_json::from_str(&json).map_err(|e| vec![format!("{}: {e}", path.display())])?;
+    let errors = check(&data);
+    if errors.is_empty() {
+        Ok(generate(&data))
+    } else {
+        Err(errors)
+    }
+}
+
+fn checked_in() -> Generated {
+    let generated = deploys_dir().join("src/generated");
+    let read = |file| fs::read_to_string(generated.join(file)).unwrap_or_default();
+    Generated {
+        known_chains: read("known_chains.rs"),
+        deployments: read("deployments.rs"),
+        chain_infos: read("chain_infos.rs"),
+        evm_chains: read("evm_chains.rs"),
+        cctp: read("cctp.rs"),
+    }
+}
+
+fn main() -> ExitCode {
+    let generated = match load() {
+        Ok(generated) => generated,
+        Err(errors) => {
+            for error in errors {
+                eprintln!("error: {error}");
+            }
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if std::env::args().any(|arg| arg == "--check") {
+        if generated != checked_in() {
+            eprintln!("error: deploys/src/generated is out of date, run wormhole-deploys-generate");
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let dir = deploys_dir().join("src/generated");
+    for (file, contents) in [
+        ("known_chains.rs", &generated.known_chains),
+        ("deployments.rs", &generated.deployments),
+        ("chain_infos.rs", &generated.chain_infos),
+        ("evm_chains.rs", &generated.evm_chains),
+        ("cctp.rs", &generated.cctp),
+    ] {
+        if let Err(e) = fs::write(dir.join(file), contents) {
+            eprintln!("error: {}: {e}", dir.join(file).display());
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn generated_files_are_up_to_date() {
+        let generated = load().unwrap_or_else(|errors| panic!("{}", errors.join("\n")));
+        assert!(
+            generated == checked_in(),
+            "deploys/src/generated is out of date, run `cargo run -p wormhole-deploys-generate`"
+        );
+    }
+
+    #[test]
+    fn rejects_inconsistent_data() {
+        let data: Data = serde_json::from_value(serde_json::json!({
+            "chains": [
+                { "name": "Ethereum", "id": 2 },
+                { "name": "Solana", "id": 1 },
+                { "name": "Bsc", "id": 4, "deployed": false },
+            ],
+            "deployments": [
+                {
+                    "ident": "ETHEREUM",
+                    "chain": "Ethereum",
+                    "name": "Ethereum",
+                    "vm": "Evm",
+                    "net_env": "MainNet",
+                    "core": "0x98f3c9e6E3fAce36bAAd05FE09d375Ef1464288b",
+                },
+                {
+                    "ident": "BSC",
+                    "chain": "Bsc",
+                    "name": "Bsc",
+                    "vm": "Evm",
+                    "net_env": "MainNet",
+                    "aliases": ["ethereum"],
+                    "core": "0x98f3c9e6E3fAce36bAAd05FE09d375Ef1464288B",
+                },
+            ],
+            "chain_infos": [
+                {
+                    "chain": "Sepolia",
+                    "consistency_levels": [1, 1],
+                    "block_time_ms": 12000,
+                    "confirmations": 64,
+                },
+            ],
+            "evm_chains": [
+                { "chain": "Ethereum", "net_env": "MainNet", "evm_chain_id": 1 },
+                { "chain": "Bsc", "net_env": "MainNet", "evm_chain_id": 1 },
+            ],
+            "cctp": [
+                {
+                    "ident": "SOLANA",
+                    "chain": "Solana",
+                    "net_env": "MainNet",
+                    "domain": 5,
+                    "token_messenger": "CCTPiPYPc6AsJuwueEnWgSgucamXDZwBd53dQ11YiKX3",
+                    "message_transmitter": "CCTPmbSD7gX1bxKPAmg77w8oFzNFpaQiQUWD43TKaecd",
+                    "usdc": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+                },
+            ],
+        }))
+        .unwrap();
+
+        assert_eq!(
+            check(&data),
+            [
+                "ETHEREUM: core \"0x98f3c9e6E3fAce36bAAd05FE09d375Ef1464288b\": invalid EIP-55 checksum",
+                "BSC: name or alias \"ethereum\" is taken by ETHEREUM",
+                "chain info: unknown chain Sepolia",
+                "duplicate MainNet EVM chain id 1",
+                "CCTP SOLANA: no MainNet deployment of Solana",
+                "Solana has no deployments, mark it with `\"deployed\": false`",
+                "Bsc has deployments but is marked with `\"deployed\": false`",
+            ]
+        );
+    }
+}
@@ -0,0 +1,451 @@
+use core::{fmt, ops::Deref, str};
+
+use sha3::{Digest, Keccak256};
+
+use crate::{
+    bech32::{Bech32, Bech32Error},
+    Vm,
+};
+
+/// The longest address [`Vm::parse_address`] returns, a NEAR account id.
+pub const MAX_ADDRESS_LEN: usize = 64;
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+impl Vm {
+    /// Render an address the way the chain displays it:
+    ///  * [`Evm`](Vm::Evm): EIP-55 checksummed hex.
+    ///  * [`Solana`](Vm::Solana): base58.
+    ///  * [`CosmWasm`](Vm::CosmWasm): bech32 with the human-readable part `hrp`, see
+    ///    [`CoreDeployment::bech32_hrp`](crate::CoreDeployment::bech32_hrp).
+    ///  * [`Sui`](Vm::Sui) and [`Aptos`](Vm::Aptos): `0x`-prefixed 32-byte hex.
+    ///  * [`Near`](Vm::Near): the account id.
+    ///  * [`Algorand`](Vm::Algorand): the decimal app id.
+    ///
+    /// Anything else, like an address of the wrong length or a CosmWasm address without `hrp`, is
+    /// rendered as `0x`-prefixed hex.
+    pub const fn format_address<'a>(
+        self,
+        address: &'a [u8],
+        hrp: Option<&'a str>,
+    ) -> FormattedAddress<'a> {
+        FormattedAddress {
+            vm: self,
+            address,
+            hrp,
+        }
+    }
+
+    /// Parse an address rendered by [`Vm::format_address`] back into its bytes, as they are
+    /// stored in a [`CoreDeployment`](crate::CoreDeployment).
+    ///
+    /// Mixed-case EVM addresses must have a valid EIP-55 checksum. Sui and Aptos addresses may
+    /// omit leading zeros. The human-readable part of a CosmWasm address is not checked.
+    pub fn parse_address(self, s: &str) -> Result<ParsedAddress, ParseAddressError> {
+        let mut out = ParsedAddress::EMPTY;
+        match self {
+            Vm::Evm => {
+                let digits = strip_0x(s)?;
+                if digits.len() != 40 {
+                    return Err(ParseAddressError::InvalidLength);
+                }
+                out.len = decode_hex(digits, &mut out.bytes)?;
+                let has_upper = digits.bytes().any(|c| c.is_ascii_uppercase());
+                let has_lower = digits.bytes().any(|c| c.is_ascii_lowercase());
+                if has_upper && has_lower {
+                    let expected = eip55(out.bytes[..20].try_into().unwrap());
+                    if digits.as_bytes() != &expected[2..] {
+                        return Err(ParseAddressError::InvalidChecksum);
+                    }
+                }
+            }
+            Vm::Solana => {
+                out.len = bs58::decode(s)
+                    .onto(&mut out.bytes[..32])
+                    .map_err(|e| match e {
+                        bs58::decode::Error::BufferTooSmall => ParseAddressError::InvalidLength,
+                        _ => ParseAddressError::InvalidBase58,
+                    })?;
+                if out.len != 32 {
+                    return Err(ParseAddressError::InvalidLength);
+                }
+            }
+            Vm::CosmWasm => {
+                let decoded = Bech32::decode(s, &mut out.bytes).map_err(|e| match e {
+                    Bech32Error::TooLong => ParseAddressError::InvalidLength,
+                    e => ParseAddressError::InvalidBech32(e),
+                })?;
+                out.len = decoded.data().len();
+            }
+            Vm::Sui | Vm::Aptos => {
+                let digits = strip_0x(s)?;
+                if digits.is_empty() || digits.len() > 64 {
+                    return Err(ParseAddressError::InvalidLength);
+                }
+                // Leading zeros may be omitted, including half of the first byte.
+                let mut padded = [b'0'; 64];
+                padded[64 - digits.len()..].copy_from_slice(digits.as_bytes());
+                out.len = decode_hex(str::from_utf8(&padded).unwrap(), &mut out.bytes)?;
+            }
+            Vm::Near => {
+                if !is_near_account_id(s.as_bytes()) {
+                    return Err(ParseAddressError::InvalidAccountId);
+                }
+                out.bytes[..s.len()].copy_from_slice(s.as_bytes());
+                out.len = s.len();
+            }
+            Vm::Algorand => {
+                if s.is_empty() || !s.bytes().all(|c| c.is_ascii_digit()) {
+                    return Err(ParseAddressError::InvalidAppId);
+                }
+                let id: u64 = s.parse().map_err(|_| ParseAddressError::InvalidAppId)?;
+                out.bytes[..8].copy_from_slice(&id.to_be_bytes());
+                out.len = 8;
+            }
+            Vm::Btc => {
+                out.len = decode_hex(strip_0x(s)?, &mut out.bytes)?;
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// An address rendered the way its chain displays it, see [`Vm::format_address`].
+///
+/// Formatting happens in the [`Display`](fmt::Display) implementation, so nothing is allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormattedAddress<'a> {
+    vm: Vm,
+    address: &'a [u8],
+    hrp: Option<&'a str>,
+}
+
+impl<'a> FormattedAddress<'a> {
+    pub const fn vm(&self) -> Vm {
+        self.vm
+    }
+
+    pub const fn address(&self) -> &'a [u8] {
+        self.address
+    }
+}
+
+impl fmt::Display for FormattedAddress<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.vm, self.address.len(), self.hrp) {
+            (Vm::Evm, 20, _) => {
+                let expected = eip55(self.address.try_into().unwrap());
+                f.write_str(str::from_utf8(&expected).map_err(|_| fmt::Error)?)
+            }
+            (Vm::Solana, 32, _) => {
+                let mut buf = [0; 44];
+                let len = bs58::encode(self.address)
+                    .onto(&mut buf[..])
+                    .map_err(|_| fmt::Error)?;
+                f.write_str(str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)?)
+            }
+            (Vm::CosmWasm, _, Some(hrp)) => fmt::Display::fmt(&Bech32::new(hrp, self.address), f),
+            (Vm::Near, _, _) if is_near_account_id(self.address) => {
+                f.write_str(str::from_utf8(self.address).map_err(|_| fmt::Error)?)
+            }
+            (Vm::Algorand, 8, _) => {
+                let id = u64::from_be_bytes(self.address.try_into().unwrap());
+                fmt::Display::fmt(&id, f)
+            }
+            _ => write_hex(f, self.address),
+        }
+    }
+}
+
+/// The bytes of an address parsed by [`Vm::parse_address`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParsedAddress {
+    bytes: [u8; MAX_ADDRESS_LEN],
+    len: usize,
+}
+
+impl ParsedAddress {
+    const EMPTY: Self = Self {
+        bytes: [0; MAX_ADDRESS_LEN],
+        len: 0,
+    };
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Deref for ParsedAddress {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl AsRef<[u8]> for ParsedAddress {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl fmt::Debug for ParsedAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ParsedAddress")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+/// Why a string could not be parsed by [`Vm::parse_address`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseAddressError {
+    /// The address decodes to the wrong number of bytes.
+    InvalidLength,
+    /// The address is not `0x`-prefixed hex.
+    InvalidHex,
+    /// The mixed-case EVM address does not match its EIP-55 checksum.
+    InvalidChecksum,
+    /// The address is not base58.
+    InvalidBase58,
+    /// The address is not bech32.
+    InvalidBech32(Bech32Error),
+    /// The address is not a valid NEAR account id.
+    InvalidAccountId,
+    /// The address is not a decimal Algorand app id.
+    InvalidAppId,
+}
+
+impl fmt::Display for ParseAddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => f.write_str("invalid address length"),
+            Self::InvalidHex => f.write_str("invalid hex address"),
+            Self::InvalidChecksum => f.write_str("invalid EIP-55 checksum"),
+            Self::InvalidBase58 => f.write_str("invalid base58 address"),
+            Self::InvalidBech32(e) => write!(f, "invalid bech32 address: {e}"),
+            Self::InvalidAccountId => f.write_str("invalid NEAR account id"),
+            Self::InvalidAppId => f.write_str("invalid Algorand app id"),
+        }
+    }
+}
+
+impl core::error::Error for ParseAddressError {}
+
+/// `0x`-prefixed hex with the letters uppercased according to EIP-55.
+fn eip55(address: &[u8; 20]) -> [u8; 42] {
+    let mut out = [0; 42];
+    out[..2].copy_from_slice(b"0x");
+    for (i, byte) in address.iter().enumerate() {
+        out[2 + 2 * i] = HEX[usize::from(byte >> 4)];
+        out[3 + 2 * i] = HEX[usize::from(byte & 15)];
+    }
+    let hash = Keccak256::digest(&out[2..]);
+    for (i, c) in out[2..].iter_mut().enumerate() {
+        let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 15;
+        if nibble >= 8 {
+            c.make_ascii_uppercase();
+        }
+    }
+    out
+}
+
+fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    use fmt::Write;
+
+    f.write_str("0x")?;
+    for byte in bytes {
+        f.write_char(HEX[usize::from(byte >> 4)].into())?;
+        f.write_char(HEX[usize::from(byte & 15)].into())?;
+    }
+    Ok(())
+}
+
+fn strip_0x(s: &str) -> Result<&str, ParseAddressError> {
+    s.strip_prefix("0x").ok_or(ParseAddressError::InvalidHex)
+}
+
+/// Decode an even number of hex digits into the start of `out`, returning the number of bytes.
+fn decode_hex(digits: &str, out: &mut [u8]) -> Result<usize, ParseAddressError> {
+    let pairs = digits.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err(ParseAddressError::InvalidHex);
+    }
+    let len = pairs.len();
+    let out = out.get_mut(..len).ok_or(ParseAddressError::InvalidLength)?;
+    for (byte, pair) in out.iter_mut().zip(pairs) {
+        let hi = hex_value(pair[0]).ok_or(ParseAddressError::InvalidHex)?;
+        let lo = hex_value(pair[1]).ok_or(ParseAddressError::InvalidHex)?;
+        *byte = (hi << 4) | lo;
+    }
+    Ok(len)
+}
+
+const fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// NEAR account ids are 2 to 64 characters of `a-z`, `0-9`, `-`, `_` and `.`, where the
+/// separators `-`, `_` and `.` may not be leading, trailing or adjacent.
+fn is_near_account_id(id: &[u8]) -> bool {
+    if !(2..=MAX_ADDRESS_LEN).contains(&id.len()) {
+        return false;
+    }
+    let mut after_separator = true;
+    for c in id {
+        match c {
+            b'a'..=b'z' | b'0'..=b'9' => after_separator = false,
+            b'-' | b'_' | b'.' if !after_separator => after_separator = true,
+            _ => return false,
+        }
+    }
+    !after_separator
+}
+
+#[cfg(test)]
+mod test {
+    use std::string::ToString;
+
+    use super::*;
+    use crate::{
+        deploys::{DEVNETS, MAINNETS, TESTNETS},
+        ContractRole,
+    };
+
+    fn round_trip(vm: Vm, s: &str) -> ParsedAddress {
+        let parsed = vm.parse_address(s).unwrap();
+        assert_eq!(vm.format_address(&parsed, None).to_string(), s);
+        parsed
+    }
+
+    // https://eips.ethereum.org/EIPS/eip-55#test-cases
+    #[test]
+    fn eip55() {
+        for s in [
+            "0x52908400098527886E0F7030069857D2E4169EE7",
+            "0xde709f2102306220921060314715629080e2fb77",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            let parsed = Vm::Evm.parse_address(s).unwrap();
+            assert_eq!(parsed.len(), 20);
+            // All-caps and all-lowercase addresses carry no checksum and are rendered with one.
+            let formatted = Vm::Evm.format_address(&parsed, None).to_string();
+            assert!(formatted.eq_ignore_ascii_case(s));
+            round_trip(Vm::Evm, &formatted);
+        }
+
+        let lower = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed";
+        assert!(Vm::Evm.parse_address(lower).is_ok());
+        assert_eq!(
+            Vm::Evm.parse_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+            Err(ParseAddressError::InvalidChecksum)
+        );
+        assert_eq!(
+            Vm::Evm.parse_address("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            Err(ParseAddressError::InvalidHex)
+        );
+        assert_eq!(
+            Vm::Evm.parse_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA"),
+            Err(ParseAddressError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn base58() {
+        let parsed = round_trip(Vm::Solana, "worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth");
+        assert_eq!(parsed.len(), 32);
+        round_trip(Vm::Solana, "11111111111111111111111111111111");
+
+        assert_eq!(
+            Vm::Solana.parse_address("worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTt0"),
+            Err(ParseAddressError::InvalidBase58)
+        );
+        assert_eq!(
+            Vm::Solana.parse_address("worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMT"),
+            Err(ParseAddressError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn short_form() {
+        for vm in [Vm::Sui, Vm::Aptos] {
+            let parsed = vm.parse_address("0x1").unwrap();
+            let mut expected = [0; 32];
+            expected[31] = 1;
+            assert_eq!(parsed.as_slice(), expected);
+            assert_eq!(
+                vm.format_address(&parsed, None).to_string(),
+                "0x0000000000000000000000000000000000000000000000000000000000000001"
+            );
+            assert_eq!(vm.parse_address("0x0100").unwrap()[30..], [1, 0]);
+
+            assert_eq!(
+                vm.parse_address("0x"),
+                Err(ParseAddressError::InvalidLength)
+            );
+            assert_eq!(vm.parse_address("1"), Err(ParseAddressError::InvalidHex));
+        }
+    }
+
+    #[test]
+    fn near() {
+        let parsed = round_trip(Vm::Near, "contract.wormhole_crypto.near");
+        assert_eq!(parsed.as_slice(), b"contract.wormhole_crypto.near");
+        round_trip(Vm::Near, "a1");
+
+        for id in ["a", "Contract.near", "-a.near", "a.near.", "a..near", "a b"] {
+            assert_eq!(
+                Vm::Near.parse_address(id),
+                Err(ParseAddressError::InvalidAccountId),
+                "{id}"
+            );
+        }
+        // Bytes which are not an account id are rendered as hex.
+        assert_eq!(Vm::Near.format_address(b"A", None).to_string(), "0x41");
+    }
+
+    #[test]
+    fn algorand() {
+        let parsed = round_trip(Vm::Algorand, "842125965");
+        assert_eq!(parsed.as_slice(), 842125965u64.to_be_bytes());
+
+        for id in ["", "-1", "0x1", "18446744073709551616"] {
+            assert_eq!(
+                Vm::Algorand.parse_address(id),
+                Err(ParseAddressError::InvalidAppId),
+                "{id}"
+            );
+        }
+    }
+
+    #[test]
+    fn built_in_addresses() {
+        for deployment in MAINNETS.iter().chain(TESTNETS).chain(DEVNETS) {
+            for role in ContractRole::ALL {
+                let Some(address) = deployment.address(role) else {
+                    continue;
+                };
+                let formatted = deployment.format_address(address).to_string();
+                let parsed = deployment
+                    .vm
+                    .parse_address(&formatted)
+                    .unwrap_or_else(|e| panic!("{} {role:?} {formatted}: {e}", deployment.name));
+                assert_eq!(
+                    parsed.as_slice(),
+                    address,
+                    "{} {role:?} {formatted}",
+                    deployment.name
+                );
+            }
+        }
+    }
+}
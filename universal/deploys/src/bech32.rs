@@ -0,0 +1,301 @@
+use core::fmt;
+
+/// The bech32 data alphabet, indexed by 5-bit value.
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+/// The checksum residue of a valid (non-`m`) bech32 string.
+const CHECKSUM_CONST: u32 = 1;
+
+const CHECKSUM_LEN: usize = 6;
+
+const fn polymod_step(checksum: u32, value: u8) -> u32 {
+    let top = checksum >> 25;
+    let mut checksum = ((checksum & 0x1ff_ffff) << 5) ^ value as u32;
+    let mut i = 0;
+    while i < GENERATOR.len() {
+        if (top >> i) & 1 == 1 {
+            checksum ^= GENERATOR[i];
+        }
+        i += 1;
+    }
+    checksum
+}
+
+/// The checksum state after feeding in the expanded human-readable part.
+const fn hrp_polymod(hrp: &[u8]) -> u32 {
+    let mut checksum = 1;
+    let mut i = 0;
+    while i < hrp.len() {
+        checksum = polymod_step(checksum, hrp[i] >> 5);
+        i += 1;
+    }
+    checksum = polymod_step(checksum, 0);
+    let mut i = 0;
+    while i < hrp.len() {
+        checksum = polymod_step(checksum, hrp[i] & 31);
+        i += 1;
+    }
+    checksum
+}
+
+const fn charset_value(c: u8) -> Option<u8> {
+    let mut i = 0;
+    while i < CHARSET.len() {
+        if CHARSET[i] == c {
+            return Some(i as u8);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Why a string is not valid bech32.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Bech32Error {
+    /// There is no human-readable part, no `1` separator, or no room for a checksum.
+    Malformed,
+    /// A character is not in the bech32 alphabet. Only lowercase strings are accepted.
+    InvalidCharacter,
+    /// The checksum does not match.
+    InvalidChecksum,
+    /// The data does not regroup into whole bytes.
+    InvalidPadding,
+    /// The data does not fit in the output buffer.
+    TooLong,
+}
+
+impl fmt::Display for Bech32Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed => f.write_str("malformed bech32 string"),
+            Self::InvalidCharacter => f.write_str("invalid bech32 character"),
+            Self::InvalidChecksum => f.write_str("invalid bech32 checksum"),
+            Self::InvalidPadding => f.write_str("invalid bech32 padding"),
+            Self::TooLong => f.write_str("bech32 data too long"),
+        }
+    }
+}
+
+impl core::error::Error for Bech32Error {}
+
+/// Verify the checksum of a lowercase bech32 string, returning the index of its first data
+/// character.
+const fn verify(bytes: &[u8]) -> Result<usize, Bech32Error> {
+    let mut separator = bytes.len();
+    while separator > 0 && bytes[separator - 1] != b'1' {
+        separator -= 1;
+    }
+    if separator <= 1 || bytes.len() - separator < CHECKSUM_LEN {
+        return Err(Bech32Error::Malformed);
+    }
+    let (hrp, _) = bytes.split_at(separator - 1);
+
+    let mut i = 0;
+    while i < hrp.len() {
+        if hrp[i] < 33 || hrp[i] > 126 || hrp[i].is_ascii_uppercase() {
+            return Err(Bech32Error::InvalidCharacter);
+        }
+        i += 1;
+    }
+
+    let mut checksum = hrp_polymod(hrp);
+    let mut i = separator;
+    while i < bytes.len() {
+        let Some(value) = charset_value(bytes[i]) else {
+            return Err(Bech32Error::InvalidCharacter);
+        };
+        checksum = polymod_step(checksum, value);
+        i += 1;
+    }
+    if checksum != CHECKSUM_CONST {
+        return Err(Bech32Error::InvalidChecksum);
+    }
+    Ok(separator)
+}
+
+/// Check the checksum of a lowercase bech32 string, failing the build when evaluated in a
+/// constant context.
+pub(crate) const fn check_checksum(address: &str) {
+    match verify(address.as_bytes()) {
+        Ok(_) => {}
+        Err(Bech32Error::InvalidCharacter) => {
+            const_panic::concat_panic!("invalid bech32 character in ", address)
+        }
+        Err(Bech32Error::InvalidChecksum) => {
+            const_panic::concat_panic!("invalid bech32 checksum in ", address)
+        }
+        Err(_) => const_panic::concat_panic!("malformed bech32 address: ", address),
+    }
+}
+
+/// Bytes rendered as a bech32 string with the given human-readable part, e.g. an address of a
+/// [`CosmWasm`](crate::Vm::CosmWasm) contract.
+///
+/// Encoding happens in the [`Display`](fmt::Display) implementation, so nothing is allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bech32<'a> {
+    hrp: &'a str,
+    data: &'a [u8],
+}
+
+impl<'a> Bech32<'a> {
+    /// The human-readable part is expected to be lowercase ASCII, like `"terra"`.
+    pub const fn new(hrp: &'a str, data: &'a [u8]) -> Self {
+        Self { hrp, data }
+    }
+
+    pub const fn hrp(&self) -> &'a str {
+        self.hrp
+    }
+
+    pub const fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Decode a lowercase bech32 string, writing the bytes it encodes to the start of `out`.
+    pub fn decode(s: &'a str, out: &'a mut [u8]) -> Result<Self, Bech32Error> {
+        let start = verify(s.as_bytes())?;
+        let data = &s.as_bytes()[start..s.len() - CHECKSUM_LEN];
+
+        // Regroup the 5-bit values into bytes, the leftover bits must be zero padding.
+        let mut acc = 0u16;
+        let mut bits = 0;
+        let mut len = 0;
+        for c in data {
+            let value = charset_value(*c).ok_or(Bech32Error::InvalidCharacter)?;
+            acc = (acc << 5) | u16::from(value);
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                *out.get_mut(len).ok_or(Bech32Error::TooLong)? = (acc >> bits) as u8;
+                len += 1;
+            }
+        }
+        if bits >= 5 || acc & ((1 << bits) - 1) != 0 {
+            return Err(Bech32Error::InvalidPadding);
+        }
+
+        Ok(Self {
+            hrp: &s[..start - 1],
+            data: &out[..len],
+        })
+    }
+}
+
+impl fmt::Display for Bech32<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        f.write_str(self.hrp)?;
+        f.write_char('1')?;
+
+        let mut checksum = hrp_polymod(self.hrp.as_bytes());
+        let mut emit = |value: u8| {
+            checksum = polymod_step(checksum, value);
+            f.write_char(CHARSET[usize::from(value)].into())
+        };
+
+        // Regroup the bytes into 5-bit values, zero-padding the last one.
+        let mut acc = 0u16;
+        let mut bits = 0;
+        for byte in self.data {
+            acc = (acc << 8) | u16::from(*byte);
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                emit(((acc >> bits) & 31) as u8)?;
+            }
+        }
+        if bits > 0 {
+            emit(((acc << (5 - bits)) & 31) as u8)?;
+        }
+
+        for _ in 0..CHECKSUM_LEN {
+            checksum = polymod_step(checksum, 0);
+        }
+        checksum ^= CHECKSUM_CONST;
+        for i in (0..CHECKSUM_LEN).rev() {
+            let value = (checksum >> (5 * i)) & 31;
+            f.write_char(CHARSET[value as usize].into())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::string::ToString;
+
+    use super::*;
+
+    fn round_trip(address: &str, hrp: &str, len: usize) {
+        let mut out = [0; 64];
+        let decoded = Bech32::decode(address, &mut out).unwrap();
+        assert_eq!(decoded.hrp(), hrp);
+        assert_eq!(decoded.data().len(), len);
+        assert_eq!(decoded.to_string(), address);
+    }
+
+    #[test]
+    fn known_addresses() {
+        round_trip("terra1dq03ugtd40zu9hcgdzrsq6z2z4hwhc9tqk2uy5", "terra", 20);
+        round_trip(
+            "terra153366q50k7t8nn7gec00hg66crnhkdggpgdtaxltaq6xrutkkz3s992fw9",
+            "terra",
+            32,
+        );
+        round_trip(
+            "wormhole1ufs3tlq4umljk0qfe8k5ya0x6hpavn897u2cnf9k0en9jr7qarqqaqfk2j",
+            "wormhole",
+            32,
+        );
+
+        let mut out = [0; 64];
+        assert_eq!(
+            Bech32::decode("terra1dq03ugtd40zu9hcgdzrsq6z2z4hwhc9tqk2uy4", &mut out),
+            Err(Bech32Error::InvalidChecksum)
+        );
+        assert_eq!(
+            Bech32::decode(
+                "terra1dq03ugtd40zu9hcgdzrsq6z2z4hwhc9tqk2uy5",
+                &mut out[..19]
+            ),
+            Err(Bech32Error::TooLong)
+        );
+    }
+
+    // https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki#test-vectors
+    #[test]
+    fn bip173_valid() {
+        for s in [
+            "a12uel5l",
+            "an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs",
+            "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+            "11qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqc8247j",
+        ] {
+            let mut out = [0; 64];
+            let decoded = Bech32::decode(s, &mut out).unwrap();
+            assert_eq!(decoded.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn bip173_invalid() {
+        for (s, err) in [
+            ("pzry9x0s0muk", Bech32Error::Malformed),
+            ("1pzry9x0s0muk", Bech32Error::Malformed),
+            ("x1b4n0q5v", Bech32Error::InvalidCharacter),
+            ("li1dgmt3", Bech32Error::Malformed),
+            ("10a06t8", Bech32Error::Malformed),
+            ("1qzzfhee", Bech32Error::Malformed),
+            // Valid, but only lowercase strings are accepted.
+            ("A12UEL5L", Bech32Error::InvalidCharacter),
+        ] {
+            let mut out = [0; 64];
+            assert_eq!(Bech32::decode(s, &mut out), Err(err), "{s}");
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use core::{fmt, hash::Hash};
+use core::{fmt, hash::Hash, str::FromStr};
+
+use crate::deploys::{DEVNETS_BY_NAME, MAINNET_BY_NAME, TESTNETS_BY_NAME};
 
 macro_rules! known_chains {
     ($($($chain:ident = $val:literal),+ $(,)?)?) => {
@@ -17,6 +19,23 @@ impl KnownChainId {
             id => Err(UnknownChainId(id)),
         }
     }
+
+    /// Every variant along with its name, in the order they are declared.
+    const VARIANTS: &'static [(Self, &'static str)] = {
+        use KnownChainId::*;
+        &[$($(($chain, stringify!($chain)),)+)?]
+    };
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for KnownChainId {
+    type Parameters = ();
+    type Strategy = proptest::sample::Select<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        use KnownChainId::*;
+        proptest::sample::select(&[$($($chain,)+)?][..])
+    }
 }
     };
 }
@@ -31,64 +50,8 @@ impl From<$ct> for $it {
     )+};
 }
 
-known_chains! {
-    Unset = 0,
-    Solana = 1,
-    Ethereum = 2,
-    Terra = 3,
-    Bsc = 4,
-    Polygon = 5,
-    Avalanche = 6,
-    Oasis = 7,
-    Algorand = 8,
-    Aurora = 9,
-    Fantom = 10,
-    Karura = 11,
-    Acala = 12,
-    Klaytn = 13,
-    Celo = 14,
-    Near = 15,
-    Moonbeam = 16,
-    Neon = 17,
-    Terra2 = 18,
-    Injective = 19,
-    Osmosis = 20,
-    Sui = 21,
-    Aptos = 22,
-    Arbitrum = 23,
-    Optimism = 24,
-    Gnosis = 25,
-    Pythnet = 26,
-    Xpla = 28,
-    Btc = 29,
-    Base = 30,
-    Sei = 32,
-    Rootstock = 33,
-    Scroll = 34,
-    Mantle = 35,
-    Blast = 36,
-    XLayer = 37,
-    Linea = 38,
-    Berachain = 39,
-    SeiEvm = 40,
-    Snaxchain = 43,
-    Wormchain = 3104,
-    Cosmoshub = 4000,
-    Evmos = 4001,
-    Kujira = 4002,
-    Neutron = 4003,
-    Celestia = 4004,
-    Stargaze = 4005,
-    Seda = 4006,
-    Dymension = 4007,
-    Provenance = 4008,
-    Sepolia = 10002,
-    ArbitrumSepolia = 10003,
-    BaseSepolia = 10004,
-    OptimismSepolia = 10005,
-    Holesky = 10006,
-    PolygonSepolia = 10007,
-}
+// Generated from `data/deployments.json`, see `wormhole-deploys-generate`.
+include!("generated/known_chains.rs");
 
 mod __private {
     use super::KnownChainId;
@@ -143,6 +106,85 @@ impl KnownChainId {
     pub const fn to_u16(self) -> u16 {
         self as u16
     }
+
+    /// Iterate over all known chains in the order they are declared, skipping
+    /// [`Unset`](KnownChainId::Unset).
+    pub fn iter() -> impl Iterator<Item = Self> + Clone {
+        Self::VARIANTS
+            .iter()
+            .map(|&(chain, _)| chain)
+            .filter(|&chain| chain != KnownChainId::Unset)
+    }
+
+    /// Look up a chain by name, ignoring case. The name is either
+    ///  * the variant name, where `_` and `-` are ignored as well, e.g. `"arbitrum_sepolia"`, or
+    ///  * the name or an alias of one of the chain's deployments, e.g. `"Goerli"`, see
+    ///    [`MAINNET_BY_NAME`], [`TESTNETS_BY_NAME`] and [`DEVNETS_BY_NAME`].
+    ///
+    /// [`Unset`](KnownChainId::Unset) is not a chain, so it has no name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        if let Some(&(chain, _)) = Self::VARIANTS
+            .iter()
+            .find(|&&(chain, variant)| chain != Self::Unset && eq_variant_name(variant, name))
+        {
+            return Some(chain);
+        }
+        MAINNET_BY_NAME
+            .get(name)
+            .or_else(|| TESTNETS_BY_NAME.get(name))
+            .or_else(|| DEVNETS_BY_NAME.get(name))
+            .and_then(|deployment| deployment.chain_id.to_known())
+    }
+}
+
+/// Whether `name` is `variant`, ignoring case, `_` and `-`.
+fn eq_variant_name(variant: &str, name: &str) -> bool {
+    let mut name = name.bytes().filter(|&c| c != b'_' && c != b'-');
+    variant
+        .bytes()
+        .all(|c| name.next().is_some_and(|n| n.eq_ignore_ascii_case(&c)))
+        && name.next().is_none()
+}
+
+/// Why a string could not be parsed as a [`KnownChainId`] or [`ChainId`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseChainIdError {
+    /// The string is neither a number nor the name of a known chain.
+    UnknownName,
+    /// The number does not fit a `u16`.
+    InvalidChainId,
+    /// The chain id is not one of a known chain.
+    UnknownChainId(UnknownChainId),
+    /// The chain id is `0`, i.e. [`Unset`](KnownChainId::Unset), which is not a chain.
+    Unset,
+}
+
+impl fmt::Display for ParseChainIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownName => f.write_str("unknown chain name"),
+            Self::InvalidChainId => f.write_str("chain id is not a u16"),
+            Self::UnknownChainId(id) => write!(f, "unknown chain id {}", id.to_u16()),
+            Self::Unset => f.write_str("chain id 0 is unset"),
+        }
+    }
+}
+
+impl core::error::Error for ParseChainIdError {}
+
+/// Parses either a chain name, see [`KnownChainId::from_name`], or the number of a known chain
+/// other than [`Unset`](KnownChainId::Unset).
+impl FromStr for KnownChainId {
+    type Err = ParseChainIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match ChainId::from_str(s)? {
+            ChainId::Known(KnownChainId::Unset) => Err(ParseChainIdError::Unset),
+            ChainId::Known(id) => Ok(id),
+            ChainId::Unknown(id) => Err(ParseChainIdError::UnknownChainId(id)),
+        }
+    }
 }
 
 impl fmt::Display for UnknownChainId {
@@ -242,6 +284,22 @@ impl ChainId {
     }
 }
 
+/// Mostly known chains, with the occasional unknown one.
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for ChainId {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        use proptest::prelude::*;
+        prop_oneof![
+            3 => any::<KnownChainId>().prop_map(ChainId::Known),
+            1 => any::<u16>().prop_map(ChainId::from_u16),
+        ]
+        .boxed()
+    }
+}
+
 impl fmt::Display for ChainId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -257,6 +315,18 @@ impl Hash for ChainId {
     }
 }
 
+impl PartialOrd for ChainId {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ChainId {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.to_u16().cmp(&other.to_u16())
+    }
+}
+
 impl From<ChainId> for u16 {
     fn from(id: ChainId) -> Self {
         id.to_u16()
@@ -270,6 +340,24 @@ impl From<u16> for ChainId {
     }
 }
 
+/// Parses either a chain name, see [`KnownChainId::from_name`], or any `u16` chain id. Chain id
+/// `0` is only accepted as a number, as it stands for all chains in governance messages.
+impl FromStr for ChainId {
+    type Err = ParseChainIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_empty() && s.bytes().all(|c| c.is_ascii_digit()) {
+            return s
+                .parse()
+                .map(Self::from_u16)
+                .map_err(|_| ParseChainIdError::InvalidChainId);
+        }
+        KnownChainId::from_name(s)
+            .map(Self::Known)
+            .ok_or(ParseChainIdError::UnknownName)
+    }
+}
+
 impl PartialEq<ChainId> for UnknownChainId {
     fn eq(&self, other: &ChainId) -> bool {
         Some(*self) == other.to_unknown()
@@ -305,7 +393,7 @@ impl PartialEq<u16> for ChainId {
 
 #[cfg(feature = "serde")]
 pub mod serde {
-    use core::fmt;
+    use core::{fmt, marker::PhantomData, str::FromStr};
 
     use serde::{de::Error as _, Deserialize as _, Deserializer, Serializer};
 
@@ -327,4 +415,144 @@ pub mod serde {
         let n = u16::deserialize(d)?;
         T::try_from(n).map_err(<D::Error>::custom)
     }
+
+    /// Use with `#[serde(with = "...::chain_id::serde::name")]` to (de)serialize a chain id as
+    /// a name, see [`serialize_name`] and [`deserialize_name_or_value`].
+    pub mod name {
+        pub use super::{deserialize_name_or_value as deserialize, serialize_name as serialize};
+    }
+
+    /// Serializes a chain id as its name if it is known, and as a `u16` otherwise. Chain id `0`
+    /// has no name, so it is serialized as a `u16` as well.
+    pub fn serialize_name<T: Clone + Into<u16>, S>(t: &T, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match super::ChainId::from_u16(t.clone().into()) {
+            super::ChainId::Known(super::KnownChainId::Unset) => s.serialize_u16(0),
+            super::ChainId::Known(id) => s.collect_str(&id),
+            super::ChainId::Unknown(id) => s.serialize_u16(id.to_u16()),
+        }
+    }
+
+    /// Deserializes a chain id from either a `u16` or a string, which may be a name or alias (see
+    /// [`KnownChainId::from_name`](super::KnownChainId::from_name)) or a number.
+    pub fn deserialize_name_or_value<'de, T, D>(d: D) -> Result<T, D::Error>
+    where
+        T: FromStr<Err: fmt::Display> + TryFrom<u16, Error: fmt::Display>,
+        D: Deserializer<'de>,
+    {
+        struct NameOrValue<T>(PhantomData<T>);
+
+        impl<T> serde::de::Visitor<'_> for NameOrValue<T>
+        where
+            T: FromStr<Err: fmt::Display> + TryFrom<u16, Error: fmt::Display>,
+        {
+            type Value = T;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a chain name or a u16 chain id")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                let id = u16::try_from(v)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))?;
+                T::try_from(id).map_err(E::custom)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                let v = u64::try_from(v)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(v), &self))?;
+                self.visit_u64(v)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        d.deserialize_any(NameOrValue(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!(
+            KnownChainId::from_name("arbitrum_sepolia"),
+            Some(KnownChainId::ArbitrumSepolia)
+        );
+        assert_eq!(
+            KnownChainId::from_name("ARBITRUM-SEPOLIA"),
+            Some(KnownChainId::ArbitrumSepolia)
+        );
+        assert_eq!(
+            KnownChainId::from_name("Goerli"),
+            Some(KnownChainId::Ethereum)
+        );
+        assert_eq!(
+            KnownChainId::from_name("fuji"),
+            Some(KnownChainId::Avalanche)
+        );
+        assert_eq!(KnownChainId::from_name("arbitrum sepolia"), None);
+        assert_eq!(KnownChainId::from_name("unset"), None);
+        assert_eq!(KnownChainId::from_name("Unset"), None);
+        assert!(KnownChainId::iter().all(|chain| chain != KnownChainId::Unset));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("Goerli".parse(), Ok(KnownChainId::Ethereum));
+        assert_eq!("2".parse(), Ok(KnownChainId::Ethereum));
+        assert_eq!("29999".parse(), Ok(ChainId::from_u16(29999)));
+        assert_eq!(
+            "29999".parse::<KnownChainId>(),
+            Err(ParseChainIdError::UnknownChainId(UnknownChainId(29999)))
+        );
+        assert_eq!(
+            "nowhere".parse::<ChainId>(),
+            Err(ParseChainIdError::UnknownName)
+        );
+        assert_eq!(
+            "65536".parse::<ChainId>(),
+            Err(ParseChainIdError::InvalidChainId)
+        );
+        assert_eq!("".parse::<ChainId>(), Err(ParseChainIdError::UnknownName));
+
+        // Chain id 0 is only accepted as a `ChainId`, and only as a number.
+        assert_eq!("0".parse::<KnownChainId>(), Err(ParseChainIdError::Unset));
+        assert_eq!("0".parse(), Ok(ChainId::Known(KnownChainId::Unset)));
+        assert_eq!(
+            "unset".parse::<KnownChainId>(),
+            Err(ParseChainIdError::UnknownName)
+        );
+        assert_eq!(
+            "unset".parse::<ChainId>(),
+            Err(ParseChainIdError::UnknownName)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_name() {
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Target(#[serde(with = "super::serde::name")] ChainId);
+
+        for (chain, json) in [
+            (ChainId::Known(KnownChainId::Solana), "\"Solana\""),
+            (ChainId::Known(KnownChainId::Unset), "0"),
+            (ChainId::from_u16(29999), "29999"),
+        ] {
+            assert_eq!(serde_json::to_string(&Target(chain)).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Target>(json).unwrap(), Target(chain));
+        }
+        assert_eq!(
+            serde_json::from_str::<Target>("\"fuji\"").unwrap(),
+            Target(ChainId::Known(KnownChainId::Avalanche))
+        );
+        assert!(serde_json::from_str::<Target>("\"unset\"").is_err());
+    }
 }
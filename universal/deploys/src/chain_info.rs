@@ -0,0 +1,152 @@
+use core::time::Duration;
+
+use crate::{ConsistencyLevel, KnownChainId};
+
+/// Protocol metadata of a chain, for estimating when the guardians will observe its messages.
+///
+/// Block times and confirmations are typical values rather than guarantees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChainInfo {
+    /// The Wormhole chain id.
+    pub chain_id: KnownChainId,
+    /// The consistency level the chain's token bridge publishes with, which most integrations
+    /// follow.
+    pub default_consistency_level: u8,
+    /// The lowest consistency level which waits for the block to be finalized. Chains which
+    /// ignore the consistency level use `0`.
+    pub finalized_consistency_level: u8,
+    /// The typical time between blocks (or slots, checkpoints, ...).
+    pub block_time: Duration,
+    /// How many blocks the guardians typically wait for on top of the block with a message
+    /// before observing it at the finalized consistency level. `0` for chains whose blocks are
+    /// final once produced.
+    pub confirmations: u32,
+    /// The limits of the [`MainNet`](crate::NetEnv::MainNet) governor, if it governs the chain.
+    pub governor: Option<GovernorLimits>,
+}
+
+impl ChainInfo {
+    /// The consistency level of a message emitted on this chain with the raw `level`.
+    pub const fn consistency_level(&self, level: u8) -> ConsistencyLevel {
+        ConsistencyLevel::new(crate::ChainId::Known(self.chain_id), level)
+    }
+
+    /// The typical time from a message being included in a block until it is final.
+    pub const fn time_to_finality(&self) -> Duration {
+        self.block_time.saturating_mul(self.confirmations)
+    }
+}
+
+/// The limits the guardians' governor puts on token bridge transfers out of a chain, in USD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GovernorLimits {
+    /// The notional value which may leave the chain within 24 hours before further transfers
+    /// are delayed.
+    pub daily_limit: u64,
+    /// Transfers of at least this value are always delayed by 24 hours, if set.
+    pub big_transaction_size: Option<u64>,
+}
+
+macro_rules! chain_infos {
+    ($($chain_id:ident {
+        consistency_levels: ($default:literal, $finalized:literal),
+        block_time_ms: $block_time_ms:literal,
+        confirmations: $confirmations:literal,
+        $(governor: ($daily_limit:literal, $big_transaction_size:expr),)?
+    },)*) => {
+/// The [`ChainInfo`] of every chain observed by the guardians.
+///
+/// The governor limits are a snapshot of the guardians' configuration, which is authoritative
+/// and changes over time.
+pub const CHAIN_INFOS: &[ChainInfo] = &[$(ChainInfo {
+    chain_id: KnownChainId::$chain_id,
+    default_consistency_level: $default,
+    finalized_consistency_level: $finalized,
+    block_time: Duration::from_millis($block_time_ms),
+    confirmations: $confirmations,
+    governor: loop {
+        $(break Some(GovernorLimits {
+            daily_limit: $daily_limit,
+            big_transaction_size: $big_transaction_size,
+        });)?
+        #[allow(unreachable_code)]
+        break None;
+    },
+},)*];
+    };
+}
+
+// Generated from `data/deployments.json`, see `wormhole-deploys-generate`.
+include!("generated/chain_infos.rs");
+
+// Lookups must be unambiguous.
+const _: () = {
+    let mut i = 0;
+    while i < CHAIN_INFOS.len() {
+        let mut j = i + 1;
+        while j < CHAIN_INFOS.len() {
+            if CHAIN_INFOS[i].chain_id as u16 == CHAIN_INFOS[j].chain_id as u16 {
+                const_panic::concat_panic!("duplicate chain info ", CHAIN_INFOS[i].chain_id as u16);
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+};
+
+/// The [`ChainInfo`] of `chain_id`, if the guardians observe it.
+pub const fn chain_info(chain_id: KnownChainId) -> Option<&'static ChainInfo> {
+    let mut i = 0;
+    while i < CHAIN_INFOS.len() {
+        if CHAIN_INFOS[i].chain_id as u16 == chain_id as u16 {
+            return Some(&CHAIN_INFOS[i]);
+        }
+        i += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ChainId, Finality};
+
+    #[test]
+    fn chain_info_levels() {
+        for info in CHAIN_INFOS {
+            let finalized = info.consistency_level(info.finalized_consistency_level);
+            assert_eq!(
+                finalized.finality(),
+                Finality::Finalized,
+                "{:?}",
+                info.chain_id
+            );
+            assert_eq!(
+                finalized.expected_latency(),
+                Some(info.block_time + info.time_to_finality())
+            );
+        }
+
+        let ethereum = chain_info(KnownChainId::Ethereum).unwrap();
+        assert!(ethereum.governor.unwrap().big_transaction_size.is_some());
+        let sepolia = chain_info(KnownChainId::Sepolia).unwrap();
+        assert_eq!(sepolia.governor, None);
+    }
+
+    #[test]
+    fn expected_latency() {
+        let ethereum = chain_info(KnownChainId::Ethereum).unwrap();
+        let instant = ethereum.consistency_level(ConsistencyLevel::EVM_INSTANT);
+        let safe = ethereum.consistency_level(ConsistencyLevel::EVM_SAFE);
+        assert_eq!(instant.finality(), Finality::Instant);
+        assert_eq!(safe.finality(), Finality::Safe);
+        assert_eq!(instant.expected_latency(), Some(ethereum.block_time));
+        assert!(safe.expected_latency() < Some(ethereum.time_to_finality()));
+
+        // Sei and its EVM are separate chains, with their own deployments.
+        let sei = ConsistencyLevel::new(ChainId::Known(KnownChainId::Sei), 200);
+        let sei_evm = ConsistencyLevel::new(ChainId::Known(KnownChainId::SeiEvm), 200);
+        assert_eq!(sei.finality(), Finality::Finalized);
+        assert_eq!(sei_evm.finality(), Finality::Instant);
+    }
+}
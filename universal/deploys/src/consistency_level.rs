@@ -0,0 +1,99 @@
+use core::time::Duration;
+
+use crate::{chain_info, deploys::EVM_NETS, ChainId, KnownChainId};
+
+/// How final a block had to be before the guardians observed a message in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Finality {
+    /// Observed as soon as the message was included in a block (or, on Solana, once the block
+    /// reached `confirmed` commitment).
+    Instant,
+    /// Observed once the block was considered safe from reorgs (the EVM `safe` block tag).
+    Safe,
+    /// Observed once the block was finalized.
+    Finalized,
+}
+
+/// The raw `consistency_level` byte of a message, together with the emitter chain whose rules
+/// give the byte its meaning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConsistencyLevel {
+    /// The chain which emitted the message.
+    pub chain: ChainId,
+    /// The consistency level as encoded in the VAA body.
+    pub level: u8,
+}
+
+impl ConsistencyLevel {
+    /// EVM consistency level requesting publication as soon as the message is in a block.
+    pub const EVM_INSTANT: u8 = 200;
+    /// EVM consistency level requesting publication once the block is `safe`.
+    pub const EVM_SAFE: u8 = 201;
+    /// Solana consistency level for messages posted with `confirmed` commitment.
+    pub const SOLANA_CONFIRMED: u8 = 1;
+    /// Solana consistency level for messages posted with `finalized` commitment.
+    pub const SOLANA_FINALIZED: u8 = 32;
+
+    pub const fn new(chain: ChainId, level: u8) -> Self {
+        Self { chain, level }
+    }
+
+    /// Interpret the consistency level according to the emitter chain.
+    ///
+    /// Chains with deterministic finality ignore the byte entirely, as do chains this crate
+    /// does not know about.
+    pub const fn finality(&self) -> Finality {
+        use KnownChainId::*;
+
+        let ChainId::Known(chain) = self.chain else {
+            return Finality::Finalized;
+        };
+        match chain {
+            Solana | Pythnet => match self.level {
+                Self::SOLANA_CONFIRMED => Finality::Instant,
+                _ => Finality::Finalized,
+            },
+            _ if is_evm(chain) => match self.level {
+                Self::EVM_INSTANT => Finality::Instant,
+                Self::EVM_SAFE => Finality::Safe,
+                _ => Finality::Finalized,
+            },
+            _ => Finality::Finalized,
+        }
+    }
+
+    /// A rough estimate of how long after the message was emitted the guardians will observe
+    /// it, derived from the emitter chain's [`ChainInfo`](crate::chain_info::ChainInfo): one
+    /// block for [`Finality::Instant`], and the [time to
+    /// finality](crate::chain_info::ChainInfo::time_to_finality) on top of it for
+    /// [`Finality::Finalized`]. Blocks are `safe` about halfway there.
+    ///
+    /// This is only a hint for scheduling and is `None` for chains without a
+    /// [`ChainInfo`](crate::chain_info::ChainInfo).
+    pub const fn expected_latency(&self) -> Option<Duration> {
+        let ChainId::Known(chain) = self.chain else {
+            return None;
+        };
+        let Some(info) = chain_info::chain_info(chain) else {
+            return None;
+        };
+        let confirmations = match self.finality() {
+            Finality::Instant => 0,
+            Finality::Safe => info.confirmations / 2,
+            Finality::Finalized => info.confirmations,
+        };
+        Some(info.block_time.saturating_mul(1 + confirmations))
+    }
+}
+
+/// Whether the chain has an EVM deployment, and hence uses the EVM consistency levels.
+const fn is_evm(chain: KnownChainId) -> bool {
+    let mut i = 0;
+    while i < EVM_NETS.len() {
+        if EVM_NETS[i].chain_id.to_u16() == chain as u16 {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
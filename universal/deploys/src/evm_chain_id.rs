@@ -0,0 +1,75 @@
+use crate::{KnownChainId, NetEnv};
+
+/// An EVM chain in one of its environments, along with its native
+/// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) chain id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EvmChain {
+    /// The Wormhole chain id.
+    pub chain_id: KnownChainId,
+    /// The environment of the chain (dev, test, main).
+    pub net_env: NetEnv,
+    /// The EIP-155 chain id.
+    pub evm_chain_id: u64,
+}
+
+macro_rules! evm_chains {
+    ($($net_env:ident { $($chain_id:ident = $evm_chain_id:literal,)* })*) => {
+/// The EIP-155 chain id of every EVM chain, in each environment it is deployed to.
+///
+/// The test environment of a chain is the network its [`TESTNETS`](crate::deploys::TESTNETS)
+/// deployment lives on, e.g. Goerli for [`Ethereum`](KnownChainId::Ethereum). Newer test
+/// networks like Sepolia have chain ids of their own.
+pub const EVM_CHAINS: &[EvmChain] = &[$($(EvmChain {
+    chain_id: KnownChainId::$chain_id,
+    net_env: NetEnv::$net_env,
+    evm_chain_id: $evm_chain_id,
+},)*)*];
+    };
+}
+
+// Generated from `data/deployments.json`, see `wormhole-deploys-generate`.
+include!("generated/evm_chains.rs");
+
+// Both lookups must be unambiguous.
+const _: () = {
+    let mut i = 0;
+    while i < EVM_CHAINS.len() {
+        let mut j = i + 1;
+        while j < EVM_CHAINS.len() {
+            let (a, b) = (&EVM_CHAINS[i], &EVM_CHAINS[j]);
+            if a.chain_id as u16 == b.chain_id as u16 && a.net_env as u8 == b.net_env as u8 {
+                const_panic::concat_panic!("duplicate EVM chain ", a.chain_id as u16);
+            }
+            if a.evm_chain_id == b.evm_chain_id {
+                const_panic::concat_panic!("duplicate EVM chain id ", a.evm_chain_id);
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+};
+
+/// The EIP-155 chain id of `chain_id` in `net_env`, if it is a known EVM chain.
+pub const fn evm_chain_id(chain_id: KnownChainId, net_env: NetEnv) -> Option<u64> {
+    let mut i = 0;
+    while i < EVM_CHAINS.len() {
+        let chain = &EVM_CHAINS[i];
+        if chain.chain_id as u16 == chain_id as u16 && chain.net_env as u8 == net_env as u8 {
+            return Some(chain.evm_chain_id);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// The known EVM chain with the EIP-155 chain id `evm_chain_id`, if any.
+pub const fn from_evm_chain_id(evm_chain_id: u64) -> Option<EvmChain> {
+    let mut i = 0;
+    while i < EVM_CHAINS.len() {
+        if EVM_CHAINS[i].evm_chain_id == evm_chain_id {
+            return Some(EVM_CHAINS[i]);
+        }
+        i += 1;
+    }
+    None
+}
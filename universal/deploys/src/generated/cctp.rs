@@ -0,0 +1,134 @@
+// @generated by wormhole-deploys-generate from data/deployments.json. Do not edit.
+
+cctp_group! {
+    // MainNets
+
+    ETHEREUM {
+        chain_id: Ethereum,
+        domain: 0,
+        circle_integration: hex!("AaDA05BD399372f0b0463744C09113c137636f6a"),
+        token_messenger: hex!("bd3fa81b58ba92a82136038b25adec7066af3155"),
+        message_transmitter: hex!("0a992d191deec32afe36203ad87d7d289a738f81"),
+        usdc: hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"),
+        net_env: MainNet,
+    },
+    AVALANCHE {
+        chain_id: Avalanche,
+        domain: 1,
+        circle_integration: hex!("09Fb06A271faFf70A651047395AaEb6265265F13"),
+        token_messenger: hex!("6b25532e1060ce10cc3b0a99e5683b91bfde6982"),
+        message_transmitter: hex!("8186359af5f57fbb40c6b14a588d2a59c0c29880"),
+        usdc: hex!("B97EF9Ef8734C71904D8002F8b6Bc66Dd9c48a6E"),
+        net_env: MainNet,
+    },
+    OPTIMISM {
+        chain_id: Optimism,
+        domain: 2,
+        circle_integration: hex!("2703483B1a5a7c577e8680de9Df8Be03c6f30e3c"),
+        token_messenger: hex!("2B4069517957735bE00ceE0fadAE88a26365528f"),
+        message_transmitter: hex!("4d41f22c5a0e5c74090899e5a8fb597a8842b3e8"),
+        usdc: hex!("0b2C639c533813f4Aa9D7837CAf62653d097Ff85"),
+        net_env: MainNet,
+    },
+    ARBITRUM {
+        chain_id: Arbitrum,
+        domain: 3,
+        circle_integration: hex!("2703483B1a5a7c577e8680de9Df8Be03c6f30e3c"),
+        token_messenger: hex!("19330d10D9Cc8751218eaf51E8885D058642E08A"),
+        message_transmitter: hex!("C30362313FBBA5cf9163F0bb16a0e01f01A896ca"),
+        usdc: hex!("af88d065e77c8cC2239327C5EDb3A432268e5831"),
+        net_env: MainNet,
+    },
+    SOLANA {
+        chain_id: Solana,
+        domain: 5,
+        circle_integration: b58_32!("Wormho1eCirc1e1ntegration111111111111111111"),
+        token_messenger: b58_32!("CCTPiPYPc6AsJuwueEnWgSgucamXDZwBd53dQ11YiKX3"),
+        message_transmitter: b58_32!("CCTPmbSD7gX1bxKPAmg77w8oFzNFpaQiQUWD43TKaecd"),
+        usdc: b58_32!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+        net_env: MainNet,
+    },
+    BASE {
+        chain_id: Base,
+        domain: 6,
+        circle_integration: hex!("03faBB06Fa052557143dC28eFCFc63FC12843f1D"),
+        token_messenger: hex!("1682Ae6375C4E4A97e4B583BC394c861A46D8962"),
+        message_transmitter: hex!("AD09780d193884d503182aD4588450C416D6F9D4"),
+        usdc: hex!("833589fCD6eDb6E08f4c7C32D4f71b54bdA02913"),
+        net_env: MainNet,
+    },
+    POLYGON {
+        chain_id: Polygon,
+        domain: 7,
+        circle_integration: hex!("0FF28217dCc90372345954563486528aa865cDd6"),
+        token_messenger: hex!("9daF8c91AEFAE50b9c0E69629D3F6Ca40cA3B3FE"),
+        message_transmitter: hex!("F3be9355363857F3e001be68856A2f96b4C39Ba9"),
+        usdc: hex!("3c499c542cEF5E3811e1192ce70d8cC03d5c3359"),
+        net_env: MainNet,
+    },
+
+    // TestNets
+
+    SEPOLIA {
+        chain_id: Sepolia,
+        domain: 0,
+        circle_integration: hex!("2703483B1a5a7c577e8680de9Df8Be03c6f30e3c"),
+        token_messenger: hex!("9f3B8679c73C2Fef8b59B4f3444d4e156fb70AA5"),
+        message_transmitter: hex!("7865fAfC2db2093669d92c0F33AeEF291086BEFD"),
+        usdc: hex!("1c7D4B196Cb0C7B01d743Fbc6116a902379C7238"),
+        net_env: TestNet,
+    },
+    FUJI {
+        chain_id: Avalanche,
+        domain: 1,
+        circle_integration: hex!("58f4c17449c90665891c42e14d34aae7a26a472e"),
+        token_messenger: hex!("eb08f243e5d3fcff26a9e38ae5520a669f4019d0"),
+        message_transmitter: hex!("a9fb1b3009dcb79e2fe346c16a604b8fa8ae0a79"),
+        usdc: hex!("5425890298aed601595a70AB815c96711a31Bc65"),
+        net_env: TestNet,
+    },
+    OPTIMISM_SEPOLIA {
+        chain_id: OptimismSepolia,
+        domain: 2,
+        circle_integration: hex!("2703483B1a5a7c577e8680de9Df8Be03c6f30e3c"),
+        token_messenger: hex!("9f3B8679c73C2Fef8b59B4f3444d4e156fb70AA5"),
+        message_transmitter: hex!("7865fAfC2db2093669d92c0F33AeEF291086BEFD"),
+        usdc: hex!("5fd84259d66Cd46123540766Be93DFE6D43130D7"),
+        net_env: TestNet,
+    },
+    ARBITRUM_SEPOLIA {
+        chain_id: ArbitrumSepolia,
+        domain: 3,
+        circle_integration: hex!("2e8f5e00a9c5d450a72700546b89e2b70dfb00f2"),
+        token_messenger: hex!("9f3B8679c73C2Fef8b59B4f3444d4e156fb70AA5"),
+        message_transmitter: hex!("7865fAfC2db2093669d92c0F33AeEF291086BEFD"),
+        usdc: hex!("75faf114eafb1BDbe2F0316DF893fd58CE46AA4d"),
+        net_env: TestNet,
+    },
+    SOLANA_TESTNET {
+        chain_id: Solana,
+        domain: 5,
+        circle_integration: b58_32!("wCCTPvsyeL9qYqbHTv3DUAyzEfYcyHoYw5c4mgcbBeW"),
+        token_messenger: b58_32!("CCTPiPYPc6AsJuwueEnWgSgucamXDZwBd53dQ11YiKX3"),
+        message_transmitter: b58_32!("CCTPmbSD7gX1bxKPAmg77w8oFzNFpaQiQUWD43TKaecd"),
+        usdc: b58_32!("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"),
+        net_env: TestNet,
+    },
+    BASE_SEPOLIA {
+        chain_id: BaseSepolia,
+        domain: 6,
+        circle_integration: hex!("2703483B1a5a7c577e8680de9Df8Be03c6f30e3c"),
+        token_messenger: hex!("9f3B8679c73C2Fef8b59B4f3444d4e156fb70AA5"),
+        message_transmitter: hex!("7865fAfC2db2093669d92c0F33AeEF291086BEFD"),
+        usdc: hex!("036CbD53842c5426634e7929541eC2318f3dCF7e"),
+        net_env: TestNet,
+    },
+    POLYGON_SEPOLIA {
+        chain_id: PolygonSepolia,
+        domain: 7,
+        token_messenger: hex!("9f3B8679c73C2Fef8b59B4f3444d4e156fb70AA5"),
+        message_transmitter: hex!("7865fAfC2db2093669d92c0F33AeEF291086BEFD"),
+        usdc: hex!("41E94Eb019C0762f9Bfcf9Fb1E58725BfB0e7582"),
+        net_env: TestNet,
+    },
+}
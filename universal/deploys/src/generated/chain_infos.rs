@@ -0,0 +1,304 @@
+// @generated by wormhole-deploys-generate from data/deployments.json. Do not edit.
+
+chain_infos! {
+    Solana {
+        consistency_levels: (32, 32),
+        block_time_ms: 400,
+        confirmations: 32,
+        governor: (50000000, Some(5000000)),
+    },
+    Ethereum {
+        consistency_levels: (1, 1),
+        block_time_ms: 12000,
+        confirmations: 64,
+        governor: (50000000, Some(5000000)),
+    },
+    Terra {
+        consistency_levels: (0, 0),
+        block_time_ms: 6000,
+        confirmations: 0,
+        governor: (150000, None),
+    },
+    Bsc {
+        consistency_levels: (15, 1),
+        block_time_ms: 3000,
+        confirmations: 2,
+        governor: (5000000, Some(500000)),
+    },
+    Polygon {
+        consistency_levels: (1, 1),
+        block_time_ms: 2000,
+        confirmations: 32,
+        governor: (5000000, Some(500000)),
+    },
+    Avalanche {
+        consistency_levels: (1, 1),
+        block_time_ms: 2000,
+        confirmations: 0,
+        governor: (5000000, Some(500000)),
+    },
+    Oasis {
+        consistency_levels: (1, 1),
+        block_time_ms: 6000,
+        confirmations: 0,
+        governor: (500000, Some(50000)),
+    },
+    Algorand {
+        consistency_levels: (0, 0),
+        block_time_ms: 2800,
+        confirmations: 0,
+        governor: (1000000, Some(100000)),
+    },
+    Aurora {
+        consistency_levels: (1, 1),
+        block_time_ms: 1000,
+        confirmations: 0,
+    },
+    Fantom {
+        consistency_levels: (1, 1),
+        block_time_ms: 1000,
+        confirmations: 0,
+        governor: (500000, Some(50000)),
+    },
+    Karura {
+        consistency_levels: (1, 1),
+        block_time_ms: 12000,
+        confirmations: 3,
+        governor: (500000, Some(50000)),
+    },
+    Acala {
+        consistency_levels: (1, 1),
+        block_time_ms: 12000,
+        confirmations: 3,
+        governor: (500000, Some(50000)),
+    },
+    Klaytn {
+        consistency_levels: (1, 1),
+        block_time_ms: 1000,
+        confirmations: 0,
+        governor: (500000, Some(50000)),
+    },
+    Celo {
+        consistency_levels: (1, 1),
+        block_time_ms: 5000,
+        confirmations: 0,
+        governor: (2000000, Some(200000)),
+    },
+    Near {
+        consistency_levels: (0, 0),
+        block_time_ms: 1200,
+        confirmations: 2,
+        governor: (150000, Some(15000)),
+    },
+    Moonbeam {
+        consistency_levels: (1, 1),
+        block_time_ms: 12000,
+        confirmations: 3,
+        governor: (5000000, Some(500000)),
+    },
+    Neon {
+        consistency_levels: (1, 1),
+        block_time_ms: 400,
+        confirmations: 0,
+    },
+    Terra2 {
+        consistency_levels: (0, 0),
+        block_time_ms: 6000,
+        confirmations: 0,
+        governor: (100000, Some(10000)),
+    },
+    Injective {
+        consistency_levels: (0, 0),
+        block_time_ms: 1000,
+        confirmations: 0,
+        governor: (500000, Some(50000)),
+    },
+    Osmosis {
+        consistency_levels: (0, 0),
+        block_time_ms: 5000,
+        confirmations: 0,
+    },
+    Sui {
+        consistency_levels: (0, 0),
+        block_time_ms: 500,
+        confirmations: 0,
+        governor: (5000000, Some(500000)),
+    },
+    Aptos {
+        consistency_levels: (0, 0),
+        block_time_ms: 250,
+        confirmations: 0,
+        governor: (1000000, Some(100000)),
+    },
+    Arbitrum {
+        consistency_levels: (1, 1),
+        block_time_ms: 250,
+        confirmations: 4800,
+        governor: (5000000, Some(500000)),
+    },
+    Optimism {
+        consistency_levels: (1, 1),
+        block_time_ms: 2000,
+        confirmations: 600,
+        governor: (5000000, Some(500000)),
+    },
+    Gnosis {
+        consistency_levels: (1, 1),
+        block_time_ms: 5000,
+        confirmations: 48,
+    },
+    Pythnet {
+        consistency_levels: (1, 32),
+        block_time_ms: 400,
+        confirmations: 32,
+    },
+    Xpla {
+        consistency_levels: (0, 0),
+        block_time_ms: 6000,
+        confirmations: 0,
+        governor: (50000, Some(5000)),
+    },
+    Base {
+        consistency_levels: (1, 1),
+        block_time_ms: 2000,
+        confirmations: 600,
+        governor: (2000000, Some(200000)),
+    },
+    Sei {
+        consistency_levels: (0, 0),
+        block_time_ms: 400,
+        confirmations: 0,
+        governor: (5000000, Some(500000)),
+    },
+    Rootstock {
+        consistency_levels: (1, 1),
+        block_time_ms: 30000,
+        confirmations: 40,
+    },
+    Scroll {
+        consistency_levels: (1, 1),
+        block_time_ms: 3000,
+        confirmations: 400,
+        governor: (500000, Some(50000)),
+    },
+    Mantle {
+        consistency_levels: (1, 1),
+        block_time_ms: 2000,
+        confirmations: 600,
+        governor: (100000, Some(10000)),
+    },
+    Blast {
+        consistency_levels: (1, 1),
+        block_time_ms: 2000,
+        confirmations: 600,
+        governor: (100000, Some(10000)),
+    },
+    XLayer {
+        consistency_levels: (1, 1),
+        block_time_ms: 3000,
+        confirmations: 400,
+        governor: (100000, Some(10000)),
+    },
+    Linea {
+        consistency_levels: (1, 1),
+        block_time_ms: 2000,
+        confirmations: 21600,
+    },
+    Berachain {
+        consistency_levels: (1, 1),
+        block_time_ms: 2000,
+        confirmations: 0,
+    },
+    SeiEvm {
+        consistency_levels: (1, 1),
+        block_time_ms: 400,
+        confirmations: 0,
+    },
+    Snaxchain {
+        consistency_levels: (1, 1),
+        block_time_ms: 2000,
+        confirmations: 600,
+        governor: (500000, Some(50000)),
+    },
+    Wormchain {
+        consistency_levels: (0, 0),
+        block_time_ms: 5000,
+        confirmations: 0,
+        governor: (500000, Some(50000)),
+    },
+    Cosmoshub {
+        consistency_levels: (0, 0),
+        block_time_ms: 6000,
+        confirmations: 0,
+    },
+    Evmos {
+        consistency_levels: (0, 0),
+        block_time_ms: 2000,
+        confirmations: 0,
+    },
+    Kujira {
+        consistency_levels: (0, 0),
+        block_time_ms: 6000,
+        confirmations: 0,
+    },
+    Neutron {
+        consistency_levels: (0, 0),
+        block_time_ms: 1000,
+        confirmations: 0,
+    },
+    Celestia {
+        consistency_levels: (0, 0),
+        block_time_ms: 6000,
+        confirmations: 0,
+    },
+    Stargaze {
+        consistency_levels: (0, 0),
+        block_time_ms: 6000,
+        confirmations: 0,
+    },
+    Seda {
+        consistency_levels: (0, 0),
+        block_time_ms: 6000,
+        confirmations: 0,
+    },
+    Dymension {
+        consistency_levels: (0, 0),
+        block_time_ms: 6000,
+        confirmations: 0,
+    },
+    Provenance {
+        consistency_levels: (0, 0),
+        block_time_ms: 5000,
+        confirmations: 0,
+    },
+    Sepolia {
+        consistency_levels: (1, 1),
+        block_time_ms: 12000,
+        confirmations: 64,
+    },
+    ArbitrumSepolia {
+        consistency_levels: (1, 1),
+        block_time_ms: 250,
+        confirmations: 4800,
+    },
+    BaseSepolia {
+        consistency_levels: (1, 1),
+        block_time_ms: 2000,
+        confirmations: 600,
+    },
+    OptimismSepolia {
+        consistency_levels: (1, 1),
+        block_time_ms: 2000,
+        confirmations: 600,
+    },
+    Holesky {
+        consistency_levels: (1, 1),
+        block_time_ms: 12000,
+        confirmations: 64,
+    },
+    PolygonSepolia {
+        consistency_levels: (1, 1),
+        block_time_ms: 2000,
+        confirmations: 32,
+    },
+}
@@ -0,0 +1,70 @@
+// @generated by wormhole-deploys-generate from data/deployments.json. Do not edit.
+
+evm_chains! {
+    MainNet {
+        Ethereum = 1,
+        Bsc = 56,
+        Polygon = 137,
+        Avalanche = 43114,
+        Oasis = 42262,
+        Aurora = 1313161554,
+        Fantom = 250,
+        Karura = 686,
+        Acala = 787,
+        Klaytn = 8217,
+        Celo = 42220,
+        Moonbeam = 1284,
+        Neon = 245022934,
+        Arbitrum = 42161,
+        Optimism = 10,
+        Gnosis = 100,
+        Base = 8453,
+        Rootstock = 30,
+        Scroll = 534352,
+        Mantle = 5000,
+        Blast = 81457,
+        XLayer = 196,
+        Linea = 59144,
+        Berachain = 80094,
+        SeiEvm = 1329,
+        Snaxchain = 2192,
+    }
+    TestNet {
+        Ethereum = 5,
+        Bsc = 97,
+        Polygon = 80001,
+        Avalanche = 43113,
+        Oasis = 42261,
+        Aurora = 1313161555,
+        Fantom = 4002,
+        Karura = 596,
+        Acala = 595,
+        Klaytn = 1001,
+        Celo = 44787,
+        Moonbeam = 1287,
+        Neon = 245022926,
+        Arbitrum = 421613,
+        Optimism = 420,
+        Gnosis = 10200,
+        Base = 84531,
+        Rootstock = 31,
+        Scroll = 534351,
+        Mantle = 5003,
+        Blast = 168587773,
+        XLayer = 195,
+        Linea = 59141,
+        Berachain = 80085,
+        SeiEvm = 1328,
+        Snaxchain = 13001,
+        Sepolia = 11155111,
+        ArbitrumSepolia = 421614,
+        BaseSepolia = 84532,
+        OptimismSepolia = 11155420,
+        Holesky = 17000,
+        PolygonSepolia = 80002,
+    }
+    DevNet {
+        Ethereum = 1337,
+        Bsc = 1397,
+    }
+}
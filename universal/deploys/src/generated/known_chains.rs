@@ -0,0 +1,60 @@
+// @generated by wormhole-deploys-generate from data/deployments.json. Do not edit.
+
+known_chains! {
+    Unset = 0,
+    Solana = 1,
+    Ethereum = 2,
+    Terra = 3,
+    Bsc = 4,
+    Polygon = 5,
+    Avalanche = 6,
+    Oasis = 7,
+    Algorand = 8,
+    Aurora = 9,
+    Fantom = 10,
+    Karura = 11,
+    Acala = 12,
+    Klaytn = 13,
+    Celo = 14,
+    Near = 15,
+    Moonbeam = 16,
+    Neon = 17,
+    Terra2 = 18,
+    Injective = 19,
+    Osmosis = 20,
+    Sui = 21,
+    Aptos = 22,
+    Arbitrum = 23,
+    Optimism = 24,
+    Gnosis = 25,
+    Pythnet = 26,
+    Xpla = 28,
+    Btc = 29,
+    Base = 30,
+    Sei = 32,
+    Rootstock = 33,
+    Scroll = 34,
+    Mantle = 35,
+    Blast = 36,
+    XLayer = 37,
+    Linea = 38,
+    Berachain = 39,
+    SeiEvm = 40,
+    Snaxchain = 43,
+    Wormchain = 3104,
+    Cosmoshub = 4000,
+    Evmos = 4001,
+    Kujira = 4002,
+    Neutron = 4003,
+    Celestia = 4004,
+    Stargaze = 4005,
+    Seda = 4006,
+    Dymension = 4007,
+    Provenance = 4008,
+    Sepolia = 10002,
+    ArbitrumSepolia = 10003,
+    BaseSepolia = 10004,
+    OptimismSepolia = 10005,
+    Holesky = 10006,
+    PolygonSepolia = 10007,
+}
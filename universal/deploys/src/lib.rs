@@ -1,18 +1,44 @@
 #![no_std]
 
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod address;
+pub mod bech32;
 pub mod chain_id;
+pub mod chain_info;
+pub mod consistency_level;
 pub mod deploys;
+pub mod evm_chain_id;
+#[cfg(feature = "std")]
+pub mod registry;
 
-pub use chain_id::{ChainId, KnownChainId};
+pub use address::{FormattedAddress, ParseAddressError, ParsedAddress};
+pub use bech32::Bech32;
+pub use chain_id::{ChainId, KnownChainId, ParseChainIdError};
+pub use chain_info::{ChainInfo, GovernorLimits};
+pub use consistency_level::{ConsistencyLevel, Finality};
+pub use evm_chain_id::EvmChain;
+#[cfg(feature = "std")]
+pub use registry::{DeploymentConfig, DeploymentRegistry, RegisteredDeployment};
 
 /// The VM used by a chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Vm {
     Evm,
     Solana,
     CosmWasm,
+    Sui,
+    Aptos,
+    Near,
+    Algorand,
+    Btc,
 }
 
 /// The environment of the chain (dev, test, main)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NetEnv {
     DevNet,
     TestNet,
@@ -20,6 +46,7 @@ pub enum NetEnv {
 }
 
 /// Struct representing the core deployment info for a chain.
+#[derive(Debug)]
 pub struct CoreDeployment {
     /// The chain id.
     pub chain_id: ChainId,
@@ -33,8 +60,271 @@ pub struct CoreDeployment {
     pub nft_bridge_address: Option<&'static [u8]>,
     /// The token router proxy contract (if any).
     pub token_router_proxy_address: Option<&'static [u8]>,
+    /// The Wormhole standard relayer contract (if any).
+    pub standard_relayer_address: Option<&'static [u8]>,
+    /// The matching engine of the liquidity layer (if any).
+    pub matching_engine_address: Option<&'static [u8]>,
+    /// The Wormhole Queries verifier contract (if any). Query responses are usually verified
+    /// against the guardian set of the core contract, so most chains have no standalone verifier.
+    pub queries_verifier_address: Option<&'static [u8]>,
+    /// The Global Accountant contract (if any).
+    pub global_accountant_address: Option<&'static [u8]>,
+    /// The post message shim used by NTT (if any).
+    pub post_message_shim_address: Option<&'static [u8]>,
+    /// The verify VAA shim used by NTT (if any).
+    pub verify_vaa_shim_address: Option<&'static [u8]>,
     /// The VM used by the chain.
     pub vm: Vm,
     /// The environment of the chain (dev, test, main).
     pub net_env: NetEnv,
 }
+
+impl CoreDeployment {
+    /// The address the token bridge (if any) emits its messages from, as it appears in VAAs.
+    ///
+    /// On Solana this is the token bridge's `emitter` PDA, and on EVM and CosmWasm chains it is the
+    /// contract address left-padded to 32 bytes. Other VMs emit from addresses derived at
+    /// runtime, which are not tracked here.
+    pub const fn token_bridge_emitter(&self) -> Option<[u8; 32]> {
+        self.emitter(ContractRole::TokenBridge)
+    }
+
+    /// The address the token router (if any) emits its messages from, as it appears in VAAs.
+    ///
+    /// On Solana the token router emits from a PDA which is not tracked here, so it has no
+    /// emitter.
+    pub const fn token_router_emitter(&self) -> Option<[u8; 32]> {
+        self.emitter(ContractRole::TokenRouter)
+    }
+
+    /// The address of the contract with the given role, if the chain has one.
+    ///
+    /// The Circle Integration contract is taken from the chain's [`CoreDeployment::cctp`].
+    pub const fn address(&self, role: ContractRole) -> Option<&'static [u8]> {
+        match role {
+            ContractRole::Core => Some(self.core_address),
+            ContractRole::TokenBridge => self.token_bridge_address,
+            ContractRole::NftBridge => self.nft_bridge_address,
+            ContractRole::TokenRouter => self.token_router_proxy_address,
+            ContractRole::StandardRelayer => self.standard_relayer_address,
+            ContractRole::CircleIntegration => match self.cctp() {
+                Some(cctp) => cctp.circle_integration_address,
+                None => None,
+            },
+            ContractRole::MatchingEngine => self.matching_engine_address,
+            ContractRole::QueriesVerifier => self.queries_verifier_address,
+            ContractRole::GlobalAccountant => self.global_accountant_address,
+            ContractRole::PostMessageShim => self.post_message_shim_address,
+            ContractRole::VerifyVaaShim => self.verify_vaa_shim_address,
+        }
+    }
+
+    /// The address the contract with the given role (if any) emits its messages from, as it
+    /// appears in VAAs.
+    ///
+    /// On Solana, messages are emitted from PDAs rather than program ids. Only the token bridge's
+    /// emitter PDA is tracked here, so no other Solana contract has an emitter, including the core
+    /// program, which never emits messages of its own. Neither do any contracts outside of EVM,
+    /// Solana and CosmWasm chains.
+    ///
+    /// The Queries verifier and the Global Accountant only verify messages, and the NTT shims
+    /// emit on behalf of the program calling them, so none of them have an emitter of their own.
+    pub const fn emitter(&self, role: ContractRole) -> Option<[u8; 32]> {
+        role_emitter(self.vm, self.net_env, role, self.address(role))
+    }
+
+    /// The chain's EIP-155 chain id, if it is an EVM chain, see
+    /// [`evm_chain_id::EVM_CHAINS`].
+    pub const fn evm_chain_id(&self) -> Option<u64> {
+        match self.chain_id.to_known() {
+            Some(chain_id) => evm_chain_id::evm_chain_id(chain_id, self.net_env),
+            None => None,
+        }
+    }
+
+    /// The chain's protocol metadata, see [`chain_info::CHAIN_INFOS`].
+    pub const fn chain_info(&self) -> Option<&'static ChainInfo> {
+        match self.chain_id.to_known() {
+            Some(chain_id) => chain_info::chain_info(chain_id),
+            None => None,
+        }
+    }
+
+    /// The limits the governor puts on transfers out of the chain, if it governs the chain.
+    /// Only [`MainNet`](NetEnv::MainNet) is governed.
+    pub const fn governor_limits(&self) -> Option<GovernorLimits> {
+        match (self.net_env, self.chain_info()) {
+            (NetEnv::MainNet, Some(info)) => info.governor,
+            _ => None,
+        }
+    }
+
+    /// The chain's CCTP deployment, if any, see [`deploys::find_cctp_by_chain`].
+    pub const fn cctp(&self) -> Option<&'static CctpDeployment> {
+        deploys::find_cctp_by_chain(self.chain_id, self.net_env)
+    }
+
+    /// The human-readable part of the chain's bech32 addresses, if it uses them.
+    pub const fn bech32_hrp(&self) -> Option<&'static str> {
+        bech32_hrp(self.chain_id)
+    }
+
+    /// Render one of the deployment's addresses, e.g. [`CoreDeployment::core_address`], the way
+    /// the chain displays it, if the chain uses bech32 addresses.
+    pub const fn bech32_address<'a>(&self, address: &'a [u8]) -> Option<Bech32<'a>> {
+        match self.bech32_hrp() {
+            Some(hrp) => Some(Bech32::new(hrp, address)),
+            None => None,
+        }
+    }
+
+    /// Render one of the deployment's addresses the way the chain displays it, see
+    /// [`Vm::format_address`].
+    pub const fn format_address<'a>(&self, address: &'a [u8]) -> FormattedAddress<'a> {
+        self.vm.format_address(address, self.bech32_hrp())
+    }
+}
+
+/// A chain's deployment of Circle's Cross-Chain Transfer Protocol (CCTP), along with Wormhole's
+/// Circle Integration contract on top of it.
+pub struct CctpDeployment {
+    /// The chain id.
+    pub chain_id: ChainId,
+    /// The CCTP domain of the chain.
+    pub domain: u32,
+    /// The Circle Integration contract (if any).
+    pub circle_integration_address: Option<&'static [u8]>,
+    /// The CCTP `TokenMessenger` contract.
+    pub token_messenger_address: &'static [u8],
+    /// The CCTP `MessageTransmitter` contract.
+    pub message_transmitter_address: &'static [u8],
+    /// The USDC token contract (on Solana, its mint).
+    pub usdc_address: &'static [u8],
+    /// The environment of the chain (dev, test, main).
+    pub net_env: NetEnv,
+}
+
+impl CctpDeployment {
+    /// The USDC address left-padded to 32 bytes, as it appears in the `token_address` of a CCTP
+    /// deposit burned on this chain.
+    pub const fn usdc_token_address(&self) -> [u8; 32] {
+        match left_pad(self.usdc_address) {
+            Some(address) => address,
+            None => panic!("USDC address longer than 32 bytes"),
+        }
+    }
+}
+
+/// The role of a contract within a [`CoreDeployment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContractRole {
+    Core,
+    TokenBridge,
+    NftBridge,
+    TokenRouter,
+    StandardRelayer,
+    /// Wormhole's Circle Integration, see [`CctpDeployment`].
+    CircleIntegration,
+    MatchingEngine,
+    QueriesVerifier,
+    GlobalAccountant,
+    PostMessageShim,
+    VerifyVaaShim,
+}
+
+impl ContractRole {
+    /// All roles, in the order their contracts are listed in a [`CoreDeployment`], with
+    /// [`ContractRole::CircleIntegration`] after the standard relayer.
+    pub const ALL: [ContractRole; 11] = [
+        ContractRole::Core,
+        ContractRole::TokenBridge,
+        ContractRole::NftBridge,
+        ContractRole::TokenRouter,
+        ContractRole::StandardRelayer,
+        ContractRole::CircleIntegration,
+        ContractRole::MatchingEngine,
+        ContractRole::QueriesVerifier,
+        ContractRole::GlobalAccountant,
+        ContractRole::PostMessageShim,
+        ContractRole::VerifyVaaShim,
+    ];
+}
+
+/// A known emitter: the deployment it belongs to and the role of its contract there.
+#[derive(Clone, Copy)]
+pub struct KnownEmitter {
+    /// The deployment the emitter belongs to.
+    pub deployment: &'static CoreDeployment,
+    /// The role of the emitting contract in the deployment.
+    pub role: ContractRole,
+}
+
+/// The human-readable part of the bech32 addresses of `chain_id`, if it uses them.
+pub(crate) const fn bech32_hrp(chain_id: ChainId) -> Option<&'static str> {
+    use KnownChainId::*;
+
+    let ChainId::Known(chain) = chain_id else {
+        return None;
+    };
+    match chain {
+        Terra | Terra2 => Some("terra"),
+        Injective => Some("inj"),
+        Osmosis => Some("osmo"),
+        Xpla => Some("xpla"),
+        Sei => Some("sei"),
+        Wormchain => Some("wormhole"),
+        Cosmoshub => Some("cosmos"),
+        Evmos => Some("evmos"),
+        Kujira => Some("kujira"),
+        Neutron => Some("neutron"),
+        Celestia => Some("celestia"),
+        Stargaze => Some("stars"),
+        Seda => Some("seda"),
+        Dymension => Some("dym"),
+        Provenance => Some("pb"),
+        _ => None,
+    }
+}
+
+/// The address a contract with `role` at `address` emits its messages from, see
+/// [`CoreDeployment::emitter`].
+pub(crate) const fn role_emitter(
+    vm: Vm,
+    net_env: NetEnv,
+    role: ContractRole,
+    address: Option<&[u8]>,
+) -> Option<[u8; 32]> {
+    let Some(address) = address else {
+        return None;
+    };
+    match (vm, role) {
+        (Vm::Solana, ContractRole::TokenBridge) => match net_env {
+            NetEnv::MainNet => Some(deploys::SOLANA_TOKEN_BRIDGE_EMITTER),
+            NetEnv::TestNet => Some(deploys::SOLANA_TESTNET_TOKEN_BRIDGE_EMITTER),
+            NetEnv::DevNet => Some(deploys::SOLANA_DEVNET_TOKEN_BRIDGE_EMITTER),
+        },
+        (
+            _,
+            ContractRole::QueriesVerifier
+            | ContractRole::GlobalAccountant
+            | ContractRole::PostMessageShim
+            | ContractRole::VerifyVaaShim,
+        ) => None,
+        (Vm::Evm | Vm::CosmWasm, _) => left_pad(address),
+        _ => None,
+    }
+}
+
+const fn left_pad(address: &[u8]) -> Option<[u8; 32]> {
+    if address.len() > 32 {
+        return None;
+    }
+    let mut out = [0; 32];
+    let offset = 32 - address.len();
+    let mut i = 0;
+    while i < address.len() {
+        out[offset + i] = address[i];
+        i += 1;
+    }
+    Some(out)
+}
@@ -0,0 +1,594 @@
+use std::{borrow::Cow, collections::BTreeMap, string::String, sync::Arc, vec::Vec};
+
+use crate::{
+    deploys::{DEVNETS, DEVNETS_BY_NAME, MAINNETS, MAINNET_BY_NAME, TESTNETS, TESTNETS_BY_NAME},
+    CctpDeployment, ChainId, ContractRole, CoreDeployment, FormattedAddress, NetEnv,
+    ParseAddressError, Vm,
+};
+
+/// A deployment to add to a [`DeploymentRegistry`], with its addresses written the way the chain
+/// displays them (see [`Vm::parse_address`]).
+///
+/// With the `serde` feature it deserializes from the same fields as the built-in deployments,
+/// e.g. in TOML:
+///
+/// ```toml
+/// [[deployments]]
+/// chain_id = "Ethereum"
+/// name = "EthereumFork"
+/// aliases = ["Ethereum"]
+/// vm = "Evm"
+/// net_env = "DevNet"
+/// core = "0xC89Ce4735882C9F0f0FE26686c53074E09B0D550"
+/// token_bridge = "0x0290FB167208Af455bB137780163b7B7a9a10C16"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct DeploymentConfig {
+    /// The chain id, written as a number or as a chain name or alias, see
+    /// [`KnownChainId::from_name`](crate::KnownChainId::from_name).
+    #[cfg_attr(feature = "serde", serde(with = "crate::chain_id::serde::name"))]
+    pub chain_id: ChainId,
+    /// The name of the chain.
+    pub name: String,
+    /// Other names the deployment can be looked up by.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub aliases: Vec<String>,
+    /// The VM used by the chain.
+    pub vm: Vm,
+    /// The environment of the chain (dev, test, main).
+    pub net_env: NetEnv,
+    /// The core contract address on the chain.
+    pub core: String,
+    /// The token bridge contract (if any).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub token_bridge: Option<String>,
+    /// The NFT bridge contract (if any).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub nft_bridge: Option<String>,
+    /// The token router proxy contract (if any).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub token_router: Option<String>,
+    /// The Wormhole standard relayer contract (if any).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub standard_relayer: Option<String>,
+    /// The matching engine of the liquidity layer (if any).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub matching_engine: Option<String>,
+    /// The Wormhole Queries verifier contract (if any).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub queries_verifier: Option<String>,
+    /// The Global Accountant contract (if any).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub global_accountant: Option<String>,
+    /// The post message shim used by NTT (if any).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub post_message_shim: Option<String>,
+    /// The verify VAA shim used by NTT (if any).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub verify_vaa_shim: Option<String>,
+}
+
+/// A deployment held by a [`DeploymentRegistry`], either one of the built-in
+/// [`CoreDeployment`]s or one added at runtime.
+///
+/// Built-in deployments are borrowed, deployments added from a [`DeploymentConfig`] own their
+/// name and addresses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisteredDeployment {
+    /// The chain id.
+    pub chain_id: ChainId,
+    /// The name of the chain.
+    pub name: Cow<'static, str>,
+    /// The core contract address on the chain.
+    pub core_address: Cow<'static, [u8]>,
+    /// The token bridge contract (if any).
+    pub token_bridge_address: Option<Cow<'static, [u8]>>,
+    /// The NFT bridge contract (if any).
+    pub nft_bridge_address: Option<Cow<'static, [u8]>>,
+    /// The token router proxy contract (if any).
+    pub token_router_proxy_address: Option<Cow<'static, [u8]>>,
+    /// The Wormhole standard relayer contract (if any).
+    pub standard_relayer_address: Option<Cow<'static, [u8]>>,
+    /// The matching engine of the liquidity layer (if any).
+    pub matching_engine_address: Option<Cow<'static, [u8]>>,
+    /// The Wormhole Queries verifier contract (if any).
+    pub queries_verifier_address: Option<Cow<'static, [u8]>>,
+    /// The Global Accountant contract (if any).
+    pub global_accountant_address: Option<Cow<'static, [u8]>>,
+    /// The post message shim used by NTT (if any).
+    pub post_message_shim_address: Option<Cow<'static, [u8]>>,
+    /// The verify VAA shim used by NTT (if any).
+    pub verify_vaa_shim_address: Option<Cow<'static, [u8]>>,
+    /// The VM used by the chain.
+    pub vm: Vm,
+    /// The environment of the chain (dev, test, main).
+    pub net_env: NetEnv,
+}
+
+impl RegisteredDeployment {
+    /// Parse the addresses of a [`DeploymentConfig`].
+    pub fn from_config(config: &DeploymentConfig) -> Result<Self, ParseAddressError> {
+        let address = |s: &str| -> Result<Cow<'static, [u8]>, ParseAddressError> {
+            Ok(Cow::Owned(config.vm.parse_address(s)?.to_vec()))
+        };
+        let optional = |s: &Option<String>| s.as_deref().map(address).transpose();
+        Ok(Self {
+            chain_id: config.chain_id,
+            name: Cow::Owned(config.name.clone()),
+            core_address: address(&config.core)?,
+            token_bridge_address: optional(&config.token_bridge)?,
+            nft_bridge_address: optional(&config.nft_bridge)?,
+            token_router_proxy_address: optional(&config.token_router)?,
+            standard_relayer_address: optional(&config.standard_relayer)?,
+            matching_engine_address: optional(&config.matching_engine)?,
+            queries_verifier_address: optional(&config.queries_verifier)?,
+            global_accountant_address: optional(&config.global_accountant)?,
+            post_message_shim_address: optional(&config.post_message_shim)?,
+            verify_vaa_shim_address: optional(&config.verify_vaa_shim)?,
+            vm: config.vm,
+            net_env: config.net_env,
+        })
+    }
+
+    /// The address of the contract with the given role, if the chain has one, see
+    /// [`CoreDeployment::address`].
+    pub fn address(&self, role: ContractRole) -> Option<&[u8]> {
+        let address = match role {
+            ContractRole::Core => return Some(&self.core_address),
+            ContractRole::TokenBridge => &self.token_bridge_address,
+            ContractRole::NftBridge => &self.nft_bridge_address,
+            ContractRole::TokenRouter => &self.token_router_proxy_address,
+            ContractRole::StandardRelayer => &self.standard_relayer_address,
+            ContractRole::CircleIntegration => {
+                return self.cctp().and_then(|cctp| cctp.circle_integration_address)
+            }
+            ContractRole::MatchingEngine => &self.matching_engine_address,
+            ContractRole::QueriesVerifier => &self.queries_verifier_address,
+            ContractRole::GlobalAccountant => &self.global_accountant_address,
+            ContractRole::PostMessageShim => &self.post_message_shim_address,
+            ContractRole::VerifyVaaShim => &self.verify_vaa_shim_address,
+        };
+        address.as_deref()
+    }
+
+    /// The address the contract with the given role (if any) emits its messages from, see
+    /// [`CoreDeployment::emitter`].
+    pub fn emitter(&self, role: ContractRole) -> Option<[u8; 32]> {
+        crate::role_emitter(self.vm, self.net_env, role, self.address(role))
+    }
+
+    /// The chain's CCTP deployment, if any, see [`CoreDeployment::cctp`].
+    pub fn cctp(&self) -> Option<&'static CctpDeployment> {
+        crate::deploys::find_cctp_by_chain(self.chain_id, self.net_env)
+    }
+
+    /// Render one of the deployment's addresses the way the chain displays it, see
+    /// [`CoreDeployment::format_address`].
+    pub fn format_address<'a>(&self, address: &'a [u8]) -> FormattedAddress<'a> {
+        self.vm
+            .format_address(address, crate::bech32_hrp(self.chain_id))
+    }
+}
+
+impl From<&'static CoreDeployment> for RegisteredDeployment {
+    fn from(deployment: &'static CoreDeployment) -> Self {
+        let borrowed = |address: Option<&'static [u8]>| address.map(Cow::Borrowed);
+        Self {
+            chain_id: deployment.chain_id,
+            name: Cow::Borrowed(deployment.name),
+            core_address: Cow::Borrowed(deployment.core_address),
+            token_bridge_address: borrowed(deployment.token_bridge_address),
+            nft_bridge_address: borrowed(deployment.nft_bridge_address),
+            token_router_proxy_address: borrowed(deployment.token_router_proxy_address),
+            standard_relayer_address: borrowed(deployment.standard_relayer_address),
+            matching_engine_address: borrowed(deployment.matching_engine_address),
+            queries_verifier_address: borrowed(deployment.queries_verifier_address),
+            global_accountant_address: borrowed(deployment.global_accountant_address),
+            post_message_shim_address: borrowed(deployment.post_message_shim_address),
+            verify_vaa_shim_address: borrowed(deployment.verify_vaa_shim_address),
+            vm: deployment.vm,
+            net_env: deployment.net_env,
+        }
+    }
+}
+
+/// Deployments indexed the same way as the built-in [`MAINNETS`], [`TESTNETS`] and [`DEVNETS`],
+/// but which can be extended or overridden at runtime, e.g. for local devnets and forks.
+///
+/// Built-in deployments and deployments added later are both handed out as
+/// [`RegisteredDeployment`]s borrowed from the registry, so code using a registry does not care
+/// where they came from. Replaced deployments are dropped once nothing refers to them.
+#[derive(Debug, Clone)]
+pub struct DeploymentRegistry {
+    mainnets: Net,
+    testnets: Net,
+    devnets: Net,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Net {
+    by_id: BTreeMap<ChainId, Arc<RegisteredDeployment>>,
+    /// Lowercase names and aliases.
+    by_name: BTreeMap<String, Arc<RegisteredDeployment>>,
+}
+
+impl Net {
+    fn insert<'a>(
+        &mut self,
+        deployment: RegisteredDeployment,
+        aliases: impl IntoIterator<Item = &'a str>,
+    ) -> &RegisteredDeployment {
+        let deployment = Arc::new(deployment);
+        if let Some(old) = self.by_id.insert(deployment.chain_id, deployment.clone()) {
+            self.by_name.retain(|_, other| !Arc::ptr_eq(other, &old));
+        }
+        self.by_name
+            .insert(deployment.name.to_ascii_lowercase(), deployment.clone());
+        for alias in aliases {
+            self.by_name
+                .insert(alias.to_ascii_lowercase(), deployment.clone());
+        }
+        &self.by_id[&deployment.chain_id]
+    }
+}
+
+impl Default for DeploymentRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeploymentRegistry {
+    /// A registry of all built-in deployments and their aliases.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        for (all, by_name) in [
+            (MAINNETS, &MAINNET_BY_NAME),
+            (TESTNETS, &TESTNETS_BY_NAME),
+            (DEVNETS, &DEVNETS_BY_NAME),
+        ] {
+            for deployment in all {
+                registry.insert(*deployment, []);
+            }
+            for (name, deployment) in by_name.entries() {
+                let net = registry.net_mut(deployment.net_env);
+                let registered = net.by_id[&deployment.chain_id].clone();
+                net.by_name
+                    .insert(name.as_str().to_ascii_lowercase(), registered);
+            }
+        }
+        registry
+    }
+
+    /// A registry without any deployments.
+    pub fn empty() -> Self {
+        Self {
+            mainnets: Net::default(),
+            testnets: Net::default(),
+            devnets: Net::default(),
+        }
+    }
+
+    fn net(&self, net_env: NetEnv) -> &Net {
+        match net_env {
+            NetEnv::MainNet => &self.mainnets,
+            NetEnv::TestNet => &self.testnets,
+            NetEnv::DevNet => &self.devnets,
+        }
+    }
+
+    fn net_mut(&mut self, net_env: NetEnv) -> &mut Net {
+        match net_env {
+            NetEnv::MainNet => &mut self.mainnets,
+            NetEnv::TestNet => &mut self.testnets,
+            NetEnv::DevNet => &mut self.devnets,
+        }
+    }
+
+    /// Add a deployment, which can also be looked up by `aliases`.
+    ///
+    /// A deployment of the same chain in the same environment is replaced, along with its name
+    /// and aliases. Names and aliases of other deployments are taken over.
+    pub fn insert<'a>(
+        &mut self,
+        deployment: impl Into<RegisteredDeployment>,
+        aliases: impl IntoIterator<Item = &'a str>,
+    ) -> &RegisteredDeployment {
+        let deployment = deployment.into();
+        self.net_mut(deployment.net_env).insert(deployment, aliases)
+    }
+
+    /// Parse and add a deployment, see [`DeploymentRegistry::insert`].
+    pub fn insert_config(
+        &mut self,
+        config: &DeploymentConfig,
+    ) -> Result<&RegisteredDeployment, ParseAddressError> {
+        let deployment = RegisteredDeployment::from_config(config)?;
+        Ok(self.insert(deployment, config.aliases.iter().map(String::as_str)))
+    }
+
+    /// Add every deployment in a document of the form `{ "deployments": [...] }`, where each
+    /// entry is a [`DeploymentConfig`].
+    ///
+    /// Any format with a serde [`Deserializer`](serde::Deserializer) works, e.g. `serde_json`
+    /// or `toml`. If any entry fails to parse, nothing is added.
+    #[cfg(feature = "serde")]
+    pub fn extend_from<'de, D>(&mut self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::{de::Error as _, Deserialize as _};
+
+        #[derive(serde::Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Document {
+            deployments: Vec<DeploymentConfig>,
+        }
+
+        let document = Document::deserialize(deserializer)?;
+        let deployments = document
+            .deployments
+            .iter()
+            .map(|config| {
+                RegisteredDeployment::from_config(config)
+                    .map_err(|e| D::Error::custom(std::format!("{}: {e}", config.name)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for (deployment, config) in deployments.into_iter().zip(&document.deployments) {
+            self.insert(deployment, config.aliases.iter().map(String::as_str));
+        }
+        Ok(())
+    }
+
+    /// Look up the deployment of `chain_id` in `net_env`.
+    pub fn by_id(&self, net_env: NetEnv, chain_id: ChainId) -> Option<&RegisteredDeployment> {
+        self.net(net_env).by_id.get(&chain_id).map(Arc::as_ref)
+    }
+
+    /// Look up a deployment in `net_env` by its case-insensitive name or one of its aliases.
+    pub fn by_name(&self, net_env: NetEnv, name: &str) -> Option<&RegisteredDeployment> {
+        self.net(net_env)
+            .by_name
+            .get(&name.to_ascii_lowercase())
+            .map(Arc::as_ref)
+    }
+
+    /// All deployments in `net_env`, ordered by chain id.
+    pub fn deployments(&self, net_env: NetEnv) -> impl Iterator<Item = &RegisteredDeployment> {
+        self.net(net_env).by_id.values().map(Arc::as_ref)
+    }
+
+    /// All names and aliases of deployments in `net_env`, in lowercase.
+    pub fn names(&self, net_env: NetEnv) -> impl Iterator<Item = &str> + '_ {
+        self.net(net_env).by_name.keys().map(|name| name.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{deploys::MAINNETS_BY_ID, KnownChainId};
+    use std::string::ToString;
+
+    const ETHEREUM: ChainId = ChainId::Known(KnownChainId::Ethereum);
+
+    fn config(chain_id: KnownChainId, name: &str, aliases: &[&str]) -> DeploymentConfig {
+        DeploymentConfig {
+            chain_id: ChainId::Known(chain_id),
+            name: name.into(),
+            aliases: aliases.iter().map(|alias| (*alias).into()).collect(),
+            vm: Vm::Evm,
+            net_env: NetEnv::DevNet,
+            core: "0xC89Ce4735882C9F0f0FE26686c53074E09B0D550".into(),
+            token_bridge: None,
+            nft_bridge: None,
+            token_router: None,
+            standard_relayer: None,
+            matching_engine: None,
+            queries_verifier: None,
+            global_accountant: None,
+            post_message_shim: None,
+            verify_vaa_shim: None,
+        }
+    }
+
+    #[test]
+    fn built_in() {
+        let registry = DeploymentRegistry::new();
+
+        let ethereum = registry.by_id(NetEnv::MainNet, ETHEREUM).unwrap();
+        let built_in = MAINNETS_BY_ID.get(&KnownChainId::Ethereum).unwrap();
+        assert_eq!(*ethereum, RegisteredDeployment::from(*built_in));
+        for role in ContractRole::ALL {
+            assert_eq!(ethereum.address(role), built_in.address(role));
+            assert_eq!(ethereum.emitter(role), built_in.emitter(role));
+        }
+        assert_eq!(
+            ethereum.format_address(&ethereum.core_address).to_string(),
+            "0x98f3c9e6E3fAce36bAAd05FE09d375Ef1464288B"
+        );
+
+        // Aliases are per environment.
+        let rootstock = registry.by_name(NetEnv::TestNet, "ROOTSTOCK").unwrap();
+        assert_eq!(rootstock.name, "RootstockTestnet");
+        assert_eq!(
+            registry.by_name(NetEnv::DevNet, "ethereum").unwrap().name,
+            "EthereumDevnet"
+        );
+        assert_eq!(
+            registry.deployments(NetEnv::MainNet).count(),
+            crate::deploys::MAINNETS.len()
+        );
+    }
+
+    #[test]
+    fn overrides() {
+        let mut registry = DeploymentRegistry::new();
+        let mut fork = config(KnownChainId::Ethereum, "EthereumFork", &["Anvil"]);
+        fork.queries_verifier = Some("0x0290FB167208Af455bB137780163b7B7a9a10C16".into());
+        let fork = registry.insert_config(&fork).unwrap();
+        assert_eq!(fork.core_address[..2], [0xc8, 0x9c]);
+        // The Queries verifier only verifies responses, so it never emits.
+        assert!(fork.address(ContractRole::QueriesVerifier).is_some());
+        assert_eq!(fork.emitter(ContractRole::QueriesVerifier), None);
+
+        let fork = registry.by_id(NetEnv::DevNet, ETHEREUM).unwrap();
+        assert_eq!(fork.name, "EthereumFork");
+        assert_eq!(registry.by_name(NetEnv::DevNet, "anvil"), Some(fork));
+        // The replaced deployment's name and aliases are gone.
+        assert_eq!(registry.by_name(NetEnv::DevNet, "EthereumDevnet"), None);
+        assert_eq!(registry.by_name(NetEnv::DevNet, "Ethereum"), None);
+        // Other environments are untouched.
+        assert_eq!(
+            registry.by_name(NetEnv::MainNet, "Ethereum").unwrap().name,
+            "Ethereum"
+        );
+
+        let mut invalid = config(KnownChainId::Bsc, "Broken", &[]);
+        invalid.core = "0x1234".into();
+        assert_eq!(
+            registry.insert_config(&invalid),
+            Err(ParseAddressError::InvalidLength)
+        );
+        assert_eq!(
+            registry
+                .by_id(NetEnv::DevNet, ChainId::Known(KnownChainId::Bsc))
+                .unwrap()
+                .name,
+            "BscDevnet"
+        );
+    }
+
+    #[test]
+    fn alias_takeover() {
+        let mut registry = DeploymentRegistry::empty();
+        registry
+            .insert_config(&config(KnownChainId::Base, "Foo", &["bar"]))
+            .unwrap();
+        // Another chain takes over the name of the first deployment.
+        registry
+            .insert_config(&config(KnownChainId::Optimism, "Baz", &["FOO"]))
+            .unwrap();
+        assert_eq!(registry.by_name(NetEnv::DevNet, "foo").unwrap().name, "Baz");
+        assert_eq!(registry.by_name(NetEnv::DevNet, "bar").unwrap().name, "Foo");
+
+        // Replacing the first deployment drops its remaining alias, but not the name it lost.
+        registry
+            .insert_config(&config(KnownChainId::Base, "Qux", &[]))
+            .unwrap();
+        assert_eq!(registry.by_name(NetEnv::DevNet, "bar"), None);
+        assert_eq!(registry.by_name(NetEnv::DevNet, "foo").unwrap().name, "Baz");
+        assert_eq!(
+            registry.names(NetEnv::DevNet).collect::<Vec<_>>(),
+            ["baz", "foo", "qux"]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json() {
+        let mut registry = DeploymentRegistry::new();
+        let json = r#"{
+            "deployments": [
+                {
+                    "chain_id": "Ethereum",
+                    "name": "EthereumFork",
+                    "vm": "Evm",
+                    "net_env": "DevNet",
+                    "core": "0xC89Ce4735882C9F0f0FE26686c53074E09B0D550",
+                    "token_bridge": "0x0290FB167208Af455bB137780163b7B7a9a10C16"
+                },
+                {
+                    "chain_id": 3104,
+                    "name": "WormchainFork",
+                    "aliases": ["gateway"],
+                    "vm": "CosmWasm",
+                    "net_env": "DevNet",
+                    "core": "wormhole1ufs3tlq4umljk0qfe8k5ya0x6hpavn897u2cnf9k0en9jr7qarqqaqfk2j"
+                }
+            ]
+        }"#;
+        registry
+            .extend_from(&mut serde_json::Deserializer::from_str(json))
+            .unwrap();
+
+        let fork = registry.by_id(NetEnv::DevNet, ETHEREUM).unwrap();
+        assert_eq!(fork.name, "EthereumFork");
+        assert_eq!(
+            fork.format_address(fork.address(ContractRole::TokenBridge).unwrap())
+                .to_string(),
+            "0x0290FB167208Af455bB137780163b7B7a9a10C16"
+        );
+        let wormchain = registry.by_name(NetEnv::DevNet, "Gateway").unwrap();
+        assert_eq!(wormchain.core_address.len(), 32);
+        assert_eq!(
+            wormchain
+                .format_address(&wormchain.core_address)
+                .to_string(),
+            "wormhole1ufs3tlq4umljk0qfe8k5ya0x6hpavn897u2cnf9k0en9jr7qarqqaqfk2j"
+        );
+
+        // Unknown fields and invalid addresses are rejected.
+        let unknown = r#"{ "deployments": [{ "chain_id": 2, "name": "X", "vm": "Evm",
+            "net_env": "DevNet", "core": "0xC89Ce4735882C9F0f0FE26686c53074E09B0D550",
+            "token_router": "0xC89Ce4735882C9F0f0FE26686c53074E09B0D550", "bridge": "" }] }"#;
+        assert!(registry
+            .extend_from(&mut serde_json::Deserializer::from_str(unknown))
+            .is_err());
+        let invalid = r#"{ "deployments": [{ "chain_id": 2, "name": "X", "vm": "Evm",
+            "net_env": "DevNet", "core": "0x1234" }] }"#;
+        let err = registry
+            .extend_from(&mut serde_json::Deserializer::from_str(invalid))
+            .unwrap_err();
+        assert!(err.to_string().starts_with("X: invalid address length"));
+
+        // A batch with one bad entry adds nothing.
+        let partial = r#"{ "deployments": [
+            { "chain_id": 6, "name": "Y", "vm": "Evm", "net_env": "DevNet",
+              "core": "0xC89Ce4735882C9F0f0FE26686c53074E09B0D550" },
+            { "chain_id": 2, "name": "X", "vm": "Evm", "net_env": "DevNet", "core": "0x1234" }
+        ] }"#;
+        let err = registry
+            .extend_from(&mut serde_json::Deserializer::from_str(partial))
+            .unwrap_err();
+        assert!(err.to_string().starts_with("X: invalid address length"));
+        assert!(registry.by_name(NetEnv::DevNet, "Y").is_none());
+        assert!(registry
+            .by_id(NetEnv::DevNet, ChainId::Known(KnownChainId::Avalanche))
+            .is_none());
+        assert_eq!(
+            registry.by_id(NetEnv::DevNet, ETHEREUM).unwrap().name,
+            "EthereumFork"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn toml() {
+        let mut registry = DeploymentRegistry::new();
+        let toml = r#"
+            [[deployments]]
+            chain_id = "Ethereum"
+            name = "EthereumFork"
+            aliases = ["Ethereum"]
+            vm = "Evm"
+            net_env = "DevNet"
+            core = "0xC89Ce4735882C9F0f0FE26686c53074E09B0D550"
+            token_bridge = "0x0290FB167208Af455bB137780163b7B7a9a10C16"
+        "#;
+        registry.extend_from(toml::Deserializer::new(toml)).unwrap();
+
+        let fork = registry.by_name(NetEnv::DevNet, "ethereum").unwrap();
+        assert_eq!(fork.name, "EthereumFork");
+        assert_eq!(fork.chain_id, ETHEREUM);
+        assert_eq!(
+            fork.emitter(ContractRole::TokenBridge).unwrap()[12..],
+            fork.token_bridge_address.as_deref().unwrap()[..]
+        );
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
 
 use alloy_primitives::FixedBytes;
 use reqwest::Url;
+use wormhole_vaas::MessageId;
 
 /// API client
 #[derive(Debug, Clone)]
@@ -76,6 +77,12 @@ impl Client {
         .map(|mut resp: ExplorerVaaResponse| resp.data.drain(..).next())
     }
 
+    pub async fn fetch_vaa_by_id(&self, id: &MessageId) -> Result<Option<ExplorerVaa>> {
+        self.send(&VaaRequest::from(*id))
+            .await
+            .map(|mut resp: ExplorerVaaResponse| resp.data.drain(..).next())
+    }
+
     pub async fn fetch_vaas_by_tx(&self, tx_hash: &str) -> Result<Vec<ExplorerVaa>> {
         self.send(&VaaByTxHashRequest::from(tx_hash))
             .await
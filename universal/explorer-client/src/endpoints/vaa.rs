@@ -1,6 +1,6 @@
 use alloy_primitives::FixedBytes;
 use reqwest::Url;
-use wormhole_vaas::{Readable, Vaa};
+use wormhole_vaas::{MessageId, Readable, Vaa};
 
 use crate::{ApiCall, Pagination, Result};
 
@@ -53,6 +53,30 @@ impl ExplorerVaa {
     pub fn deser_vaa(&self) -> Result<Vaa> {
         Vaa::read(&mut self.vaa.as_slice()).map_err(Into::into)
     }
+
+    pub fn message_id(&self) -> MessageId {
+        MessageId::new(
+            self.emitter_chain.into(),
+            self.emitter_addr.0,
+            self.sequence.into(),
+        )
+    }
+}
+
+impl From<&ExplorerVaa> for MessageId {
+    fn from(vaa: &ExplorerVaa) -> Self {
+        vaa.message_id()
+    }
+}
+
+impl From<MessageId> for VaaRequest {
+    fn from(id: MessageId) -> Self {
+        Self {
+            chain_id: Some(id.chain.into()),
+            emitter: Some(id.emitter.into()),
+            sequence: Some(id.sequence),
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -87,5 +111,6 @@ mod test {
 
         let res: super::ExplorerVaaResponse = serde_json::from_str(json).unwrap();
         assert_eq!(res.data.len(), 1);
+        assert_eq!(res.data[0].message_id().to_string(), res.data[0].id);
     }
 }
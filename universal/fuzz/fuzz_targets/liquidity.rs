@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    wormhole_fuzz::check_liquidity(data);
+    wormhole_fuzz::check_liquidity_deposit(data);
+});
@@ -0,0 +1,340 @@
+//! Differential checks between the owned parsers in `wormhole-vaas` and the zero-copy parsers in
+//! `wormhole-raw-vaas`, shared by the fuzz targets.
+//!
+//! Every check feeds the same bytes to both parsers and panics if:
+//! - one accepts what the other rejects,
+//! - they extract different fields, or
+//! - the owned value does not re-encode to exactly the bytes it was read from.
+
+use wormhole_io::{Readable, TypePrefixedPayload, Writeable};
+use wormhole_raw_vaas as raw;
+use wormhole_vaas::{
+    payloads::{
+        gov::{core_bridge, token_bridge as token_bridge_gov, GovernanceMessage},
+        liquidity::{CctpDeposit, DepositMessage, LiquidityLayerMessage},
+        token_bridge::TokenBridgeMessage,
+    },
+    utils::U256,
+    VaaBody, VaaHeader,
+};
+
+/// Run the check selected by the first byte against the remaining bytes.
+pub fn check_any(data: &[u8]) {
+    let Some((selector, data)) = data.split_first() else {
+        return;
+    };
+
+    match selector % 9 {
+        0 => check_header(data),
+        1 => check_body(data),
+        2 => check_vaa(data),
+        3 => check_core_decree(data),
+        4 => check_token_bridge_decree(data),
+        5 => check_token_bridge_message(data),
+        6 => check_cctp(data),
+        7 => check_liquidity(data),
+        _ => check_liquidity_deposit(data),
+    }
+}
+
+pub fn check_header(data: &[u8]) {
+    let owned = VaaHeader::read(&mut &data[..]).ok();
+    let raw = raw::Header::parse(data).ok();
+    let Some((owned, raw)) = agree(owned, raw, data) else {
+        return;
+    };
+
+    assert_round_trip(&owned, raw.as_ref());
+    assert_header_eq(&owned, &raw);
+}
+
+pub fn check_body(data: &[u8]) {
+    let owned = VaaBody::read(&mut &data[..]).ok();
+    let raw = raw::Body::parse(data).ok();
+    let Some((owned, raw)) = agree(owned, raw, data) else {
+        return;
+    };
+
+    assert_round_trip(&owned, data);
+    assert_body_eq(&owned, &raw);
+}
+
+pub fn check_vaa(data: &[u8]) {
+    let owned = wormhole_vaas::Vaa::read(&mut &data[..]).ok();
+    let raw = raw::Vaa::parse(data).ok();
+    let Some((owned, raw)) = agree(owned, raw, data) else {
+        return;
+    };
+
+    assert_round_trip(&owned, data);
+    assert_header_eq(&owned.header, &raw.header());
+    assert_body_eq(&owned.body, &raw.body());
+}
+
+pub fn check_core_decree(data: &[u8]) {
+    // The raw parser also checks the module and insists on at least one guardian in a guardian
+    // set update. The owned types leave both to the caller.
+    let owned = GovernanceMessage::<core_bridge::Decree>::read_slice(data)
+        .ok()
+        .filter(|msg| msg.header.module == core_bridge::GOVERNANCE_MODULE)
+        .filter(|msg| match &msg.decree {
+            core_bridge::Decree::GuardianSetUpdate(inner) => !inner.guardians.is_empty(),
+            _ => true,
+        });
+    let raw = raw::core::CoreBridgeGovPayload::parse(data).ok();
+    let Some((owned, raw)) = agree(owned, raw, data) else {
+        return;
+    };
+
+    assert_round_trip(&owned, data);
+
+    use raw::core::CoreBridgeDecree as Raw;
+    match (&owned.decree, raw.decree()) {
+        (core_bridge::Decree::ContractUpgrade(owned), Raw::ContractUpgrade(raw)) => {
+            assert_eq!(owned.chain.to_u16(), raw.chain());
+            assert_eq!(owned.implementation, raw.implementation());
+        }
+        (core_bridge::Decree::GuardianSetUpdate(owned), Raw::GuardianSetUpdate(raw)) => {
+            assert_eq!(owned.new_index, raw.new_index());
+            assert_eq!(owned.guardians.len(), usize::from(raw.num_guardians()));
+            for (i, guardian) in owned.guardians.iter().enumerate() {
+                assert_eq!(*guardian, raw.guardian_at(i));
+            }
+        }
+        (core_bridge::Decree::SetMessageFee(owned), Raw::SetMessageFee(raw)) => {
+            assert_eq!(owned.chain.to_u16(), raw.chain());
+            assert_eq!(be_bytes(owned.fee), raw.fee());
+        }
+        (core_bridge::Decree::TransferFees(owned), Raw::TransferFees(raw)) => {
+            assert_eq!(owned.chain.to_u16(), raw.chain());
+            assert_eq!(be_bytes(owned.amount), raw.amount());
+            assert_eq!(owned.recipient, raw.recipient());
+        }
+        (core_bridge::Decree::RecoverChainId(owned), Raw::RecoverChainId(raw)) => {
+            assert_eq!(owned.recovered_chain.to_u16(), raw.recovered_chain());
+            assert_eq!(be_bytes(owned.evm_chain_id), raw.evm_chain_id());
+            assert_eq!(owned.new_chain.to_u16(), raw.new_chain());
+        }
+        (owned, raw) => panic!("decree mismatch: {owned:?} vs {raw:?}"),
+    }
+}
+
+pub fn check_token_bridge_decree(data: &[u8]) {
+    // As with the core bridge, only the raw parser checks the module.
+    let owned = GovernanceMessage::<token_bridge_gov::Decree>::read_slice(data)
+        .ok()
+        .filter(|msg| msg.header.module == token_bridge_gov::GOVERNANCE_MODULE);
+    let raw = raw::token_bridge::TokenBridgeGovPayload::parse(data).ok();
+    let Some((owned, raw)) = agree(owned, raw, data) else {
+        return;
+    };
+
+    assert_round_trip(&owned, data);
+
+    use raw::token_bridge::TokenBridgeDecree as Raw;
+    match (&owned.decree, raw.decree()) {
+        (token_bridge_gov::Decree::RegisterChain(owned), Raw::RegisterChain(raw)) => {
+            assert_eq!(owned.foreign_chain.to_u16(), raw.foreign_chain());
+            assert_eq!(owned.foreign_emitter, raw.foreign_emitter());
+        }
+        (token_bridge_gov::Decree::ContractUpgrade(owned), Raw::ContractUpgrade(raw)) => {
+            assert_eq!(owned.chain.to_u16(), raw.chain());
+            assert_eq!(owned.implementation, raw.implementation());
+        }
+        (token_bridge_gov::Decree::RecoverChainId(owned), Raw::RecoverChainId(raw)) => {
+            assert_eq!(owned.recovered_chain.to_u16(), raw.recovered_chain());
+            assert_eq!(be_bytes(owned.evm_chain_id), raw.evm_chain_id());
+            assert_eq!(owned.new_chain.to_u16(), raw.new_chain());
+        }
+        (owned, raw) => panic!("decree mismatch: {owned:?} vs {raw:?}"),
+    }
+}
+
+pub fn check_token_bridge_message(data: &[u8]) {
+    let owned = TokenBridgeMessage::read_slice(data).ok();
+    let raw = raw::token_bridge::TokenBridgeMessage::parse(data).ok();
+    let Some((owned, raw)) = agree(owned, raw, data) else {
+        return;
+    };
+
+    assert_round_trip(&owned, data);
+
+    use raw::token_bridge::TokenBridgeMessage as Raw;
+    match (&owned, raw) {
+        (TokenBridgeMessage::Transfer(owned), Raw::Transfer(raw)) => {
+            assert_eq!(be_bytes(owned.norm_amount.0), raw.amount());
+            assert_eq!(owned.token_address, raw.token_address());
+            assert_eq!(owned.token_chain.to_u16(), raw.token_chain());
+            assert_eq!(owned.recipient, raw.recipient());
+            assert_eq!(owned.recipient_chain.to_u16(), raw.recipient_chain());
+            assert_eq!(be_bytes(owned.norm_relayer_fee.0), raw.relayer_fee());
+        }
+        (TokenBridgeMessage::Attestation(owned), Raw::Attestation(raw)) => {
+            assert_eq!(owned.token_address, raw.token_address());
+            assert_eq!(owned.token_chain.to_u16(), raw.token_chain());
+            assert_eq!(owned.decimals, raw.decimals());
+            assert_eq!(owned.symbol_string(), raw.symbol());
+            assert_eq!(owned.name_string(), raw.name());
+        }
+        (TokenBridgeMessage::TransferWithMessage(owned), Raw::TransferWithMessage(raw)) => {
+            assert_eq!(be_bytes(owned.norm_amount.0), raw.amount());
+            assert_eq!(owned.token_address, raw.token_address());
+            assert_eq!(owned.token_chain.to_u16(), raw.token_chain());
+            assert_eq!(owned.redeemer, raw.redeemer());
+            assert_eq!(owned.redeemer_chain.to_u16(), raw.redeemer_chain());
+            assert_eq!(owned.sender, raw.sender());
+            assert_eq!(owned.payload, raw.payload().as_ref());
+        }
+        (owned, raw) => panic!("message mismatch: {owned:?} vs {raw:?}"),
+    }
+}
+
+pub fn check_cctp(data: &[u8]) {
+    let raw = raw::cctp::WormholeCctpMessage::parse(data).ok();
+
+    // Types 2 through 10 are reserved by the raw parser, which has nothing to compare them to.
+    if let Some(raw::cctp::WormholeCctpMessage::ReservedUnknown(_)) = raw {
+        assert!(CctpDeposit::read_slice(data).is_err());
+        return;
+    }
+
+    let owned = CctpDeposit::read_slice(data).ok();
+    let raw = raw.map(raw::cctp::WormholeCctpMessage::to_deposit_unchecked);
+    let Some((owned, raw)) = agree(owned, raw, data) else {
+        return;
+    };
+
+    assert_eq!(owned.payload_written_size(), data.len());
+    assert_eq!(owned.to_payload_vec(), data);
+    assert_deposit_eq(&owned, &raw);
+}
+
+pub fn check_liquidity(data: &[u8]) {
+    let owned = LiquidityLayerMessage::read_slice(data).ok();
+    let raw = raw::liquidity::LiquidityLayerMessage::parse(data).ok();
+    let Some((owned, raw)) = agree(owned, raw, data) else {
+        return;
+    };
+
+    assert_round_trip(&owned, data);
+
+    use raw::liquidity::LiquidityLayerMessage as Raw;
+    match (&owned, raw) {
+        (LiquidityLayerMessage::Deposit(owned), Raw::Deposit(raw)) => {
+            assert_deposit_eq(owned, &raw);
+        }
+        (LiquidityLayerMessage::FastMarketOrder(owned), Raw::FastMarketOrder(raw)) => {
+            assert_eq!(owned.amount_in, raw.amount_in());
+            assert_eq!(owned.min_amount_out, raw.min_amount_out());
+            assert_eq!(owned.target_chain.to_u16(), raw.target_chain());
+            assert_eq!(owned.redeemer, raw.redeemer());
+            assert_eq!(owned.sender, raw.sender());
+            assert_eq!(owned.refund_address, raw.refund_address());
+            assert_eq!(owned.max_fee, raw.max_fee());
+            assert_eq!(owned.init_auction_fee, raw.init_auction_fee());
+            assert_eq!(owned.deadline, raw.deadline());
+            assert_eq!(
+                owned.redeemer_message.as_slice(),
+                raw.redeemer_message().as_ref()
+            );
+        }
+        (LiquidityLayerMessage::FastFill(owned), Raw::FastFill(raw)) => {
+            assert_eq!(owned.fill_amount, raw.fill_amount());
+            assert_eq!(owned.source_chain.to_u16(), raw.source_chain());
+            assert_eq!(owned.order_sender, raw.order_sender());
+            assert_eq!(owned.redeemer, raw.redeemer());
+            assert_eq!(
+                owned.redeemer_message.as_slice(),
+                raw.redeemer_message().as_ref()
+            );
+        }
+        (owned, raw) => panic!("message mismatch: {owned:?} vs {raw:?}"),
+    }
+}
+
+pub fn check_liquidity_deposit(data: &[u8]) {
+    let owned = DepositMessage::read_slice(data).ok();
+    let raw = raw::liquidity::LiquidityLayerDepositMessage::parse(data).ok();
+    let Some((owned, raw)) = agree(owned, raw, data) else {
+        return;
+    };
+
+    assert_round_trip(&owned, data);
+
+    use raw::liquidity::LiquidityLayerDepositMessage as Raw;
+    match (&owned, raw) {
+        (DepositMessage::Fill(owned), Raw::Fill(raw)) => {
+            assert_eq!(owned.source_chain.to_u16(), raw.source_chain());
+            assert_eq!(owned.order_sender, raw.order_sender());
+            assert_eq!(owned.redeemer, raw.redeemer());
+            assert_eq!(
+                owned.redeemer_message.as_slice(),
+                raw.redeemer_message().as_ref()
+            );
+        }
+        (DepositMessage::SlowOrderResponse(owned), Raw::SlowOrderResponse(raw)) => {
+            assert_eq!(owned.base_fee, raw.base_fee());
+        }
+        (owned, raw) => panic!("message mismatch: {owned:?} vs {raw:?}"),
+    }
+}
+
+/// Both parsers must agree on whether `data` is valid. Returns both parsed values if it is.
+fn agree<O, R>(owned: Option<O>, raw: Option<R>, data: &[u8]) -> Option<(O, R)>
+where
+    O: std::fmt::Debug,
+    R: std::fmt::Debug,
+{
+    match (owned, raw) {
+        (Some(owned), Some(raw)) => Some((owned, raw)),
+        (None, None) => None,
+        (owned, raw) => panic!(
+            "parsers disagree on {}: owned {owned:?}, raw {raw:?}",
+            hex(data)
+        ),
+    }
+}
+
+fn assert_round_trip<T: Writeable>(owned: &T, encoded: &[u8]) {
+    assert_eq!(owned.written_size(), encoded.len());
+    assert_eq!(owned.to_vec(), encoded);
+}
+
+fn assert_header_eq(owned: &VaaHeader, raw: &raw::Header<'_>) {
+    assert_eq!(owned.version, raw.version());
+    assert_eq!(owned.guardian_set_index, raw.guardian_set_index());
+    assert_eq!(owned.signatures.len(), usize::from(raw.signature_count()));
+    for (owned, raw) in owned.signatures.iter().zip(raw.signatures()) {
+        assert_eq!(owned.guardian_set_index, raw.guardian_index());
+        assert_eq!(owned.signature, raw.signature());
+    }
+}
+
+fn assert_body_eq(owned: &VaaBody, raw: &raw::Body<'_>) {
+    assert_eq!(owned.timestamp, raw.timestamp());
+    assert_eq!(owned.nonce, raw.nonce());
+    assert_eq!(owned.emitter_chain.to_u16(), raw.emitter_chain());
+    assert_eq!(owned.emitter_address, raw.emitter_address());
+    assert_eq!(owned.sequence, raw.sequence());
+    assert_eq!(owned.consistency_level, raw.consistency_level());
+    assert_eq!(owned.payload_bytes(), Some(raw.payload().as_ref()));
+}
+
+fn assert_deposit_eq(owned: &CctpDeposit, raw: &raw::cctp::Deposit<'_>) {
+    assert_eq!(owned.token_address, raw.token_address());
+    assert_eq!(owned.amount, raw.amount());
+    assert_eq!(owned.source_cctp_domain, raw.source_cctp_domain());
+    assert_eq!(owned.destination_cctp_domain, raw.destination_cctp_domain());
+    assert_eq!(owned.cctp_nonce, raw.cctp_nonce());
+    assert_eq!(owned.burn_source, raw.burn_source());
+    assert_eq!(owned.mint_recipient, raw.mint_recipient());
+    assert_eq!(owned.payload.as_slice(), raw.payload().as_ref());
+}
+
+fn be_bytes(value: U256) -> [u8; 32] {
+    value.to_be_bytes()
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -0,0 +1,68 @@
+//! [`proptest`] strategies for the encoding wrappers.
+#![cfg(feature = "proptest")]
+
+use std::fmt;
+
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    collection,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+use crate::WriteableSequence;
+
+/// Longest sequence generated, so that the length fits any length prefix.
+const MAX_LEN: usize = u8::MAX as usize;
+
+impl<Length, T> Arbitrary for WriteableSequence<Length, Vec<T>>
+where
+    usize: TryInto<Length>,
+    Length: fmt::Debug + 'static,
+    T: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        collection::vec(any::<T>(), 0..=MAX_LEN)
+            .prop_map(Self::new)
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use wormhole_deploys::ChainId;
+
+    use super::*;
+    use crate::{Readable, Writeable};
+
+    fn roundtrip<T: Readable + Writeable + PartialEq + fmt::Debug>(value: T) {
+        let encoded = value.to_vec();
+        assert_eq!(value.written_size(), encoded.len());
+        assert_eq!(T::read(&mut encoded.as_slice()).unwrap(), value);
+    }
+
+    proptest! {
+        #[test]
+        fn chain_id(value: ChainId) {
+            roundtrip(value);
+        }
+
+        #[test]
+        fn sequence_u8(value: WriteableSequence<u8, Vec<u8>>) {
+            roundtrip(value);
+        }
+
+        #[test]
+        fn sequence_u16(value: WriteableSequence<u16, Vec<u64>>) {
+            roundtrip(value);
+        }
+
+        #[test]
+        fn sequence_u32(value: WriteableSequence<u32, Vec<[u8; 20]>>) {
+            roundtrip(value);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub use wormhole_deploys as deploys;
 
+mod arbitrary;
 mod payload;
 mod read_write;
 
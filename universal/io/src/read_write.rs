@@ -134,7 +134,7 @@ impl<const N: usize> Readable for [u8; N] {
 
 impl<const N: usize> Writeable for [u8; N] {
     fn written_size(&self) -> usize {
-        <u8 as Readable>::SIZE.unwrap()
+        N
     }
 
     fn write<W>(&self, writer: &mut W) -> io::Result<()>
@@ -474,7 +474,13 @@ where
     Sequence: ?Sized,
 {
     fn written_size(&self) -> usize {
-        self.sequence.into_iter().map(|s| s.written_size()).sum()
+        // An overflowing length fails to write anyway, so only the sequence is counted.
+        let len = self.try_encoded_len().map_or(0, |len| len.written_size());
+        len + self
+            .sequence
+            .into_iter()
+            .map(|s| s.written_size())
+            .sum::<usize>()
     }
     fn write<W>(&self, writer: &mut W) -> io::Result<()>
     where
@@ -616,6 +622,7 @@ pub mod test {
 
         let expected = hex!("01020810204045");
         assert_eq!(encoded, expected);
+        assert_eq!(data.written_size(), encoded.len());
     }
 
     #[test]
@@ -642,6 +649,7 @@ pub mod test {
 
         let expected = hex!("1f416c6c20796f75722062617365206172652062656c6f6e6720746f2075732e");
         assert_eq!(encoded, expected);
+        assert_eq!(bytes.written_size(), encoded.len());
     }
 
     #[test]
@@ -655,6 +663,7 @@ pub mod test {
 
         let expected = hex!("001f416c6c20796f75722062617365206172652062656c6f6e6720746f2075732e");
         assert_eq!(encoded, expected);
+        assert_eq!(bytes.written_size(), encoded.len());
 
         let mut reader = std::io::Cursor::new(&mut encoded);
         let decoded: WriteableSequence<u16, Vec<u8>> =
@@ -675,6 +684,7 @@ pub mod test {
         let expected =
             hex!("0000001f416c6c20796f75722062617365206172652062656c6f6e6720746f2075732e");
         assert_eq!(encoded, expected);
+        assert_eq!(bytes.written_size(), encoded.len());
     }
 
     #[test]
@@ -35,8 +35,8 @@ impl<'a> CircleIntegrationGovPayload<'a> {
     }
 
     pub fn parse(span: &'a [u8]) -> Result<Self, &'static str> {
-        if span.is_empty() {
-            return Err("CircleIntegrationGovPayload span too short. Need at least 1 byte");
+        if span.len() < 32 {
+            return Err("CircleIntegrationGovPayload span too short. Need at least 32 bytes");
         }
 
         if &span[..32] != GOV_MODULE {
@@ -71,7 +71,7 @@ impl<'a> WormholeCctpMessage<'a> {
         self.as_ref()
     }
 
-    pub fn deposit(&self) -> Option<&Deposit> {
+    pub fn deposit(&self) -> Option<&Deposit<'_>> {
         match self {
             Self::Deposit(inner) => Some(inner),
             _ => None,
@@ -34,8 +34,8 @@ impl<'a> CoreBridgeGovPayload<'a> {
     }
 
     pub fn parse(span: &'a [u8]) -> Result<Self, &'static str> {
-        if span.is_empty() {
-            return Err("CoreBridgeGovPayload span too short. Need at least 1 byte");
+        if span.len() < 32 {
+            return Err("CoreBridgeGovPayload span too short. Need at least 32 bytes");
         }
 
         if &span[..32] != GOV_MODULE {
@@ -83,7 +83,7 @@ impl<'a> CoreBridgeDecree<'a> {
         self.as_ref()
     }
 
-    pub fn contract_upgrade(&self) -> Option<&ContractUpgrade> {
+    pub fn contract_upgrade(&self) -> Option<&ContractUpgrade<'_>> {
         match self {
             CoreBridgeDecree::ContractUpgrade(inner) => Some(inner),
             _ => None,
@@ -97,7 +97,7 @@ impl<'a> CoreBridgeDecree<'a> {
         }
     }
 
-    pub fn guardian_set_update(&self) -> Option<&GuardianSetUpdate> {
+    pub fn guardian_set_update(&self) -> Option<&GuardianSetUpdate<'_>> {
         match self {
             CoreBridgeDecree::GuardianSetUpdate(inner) => Some(inner),
             _ => None,
@@ -111,7 +111,7 @@ impl<'a> CoreBridgeDecree<'a> {
         }
     }
 
-    pub fn set_message_fee(&self) -> Option<&SetMessageFee> {
+    pub fn set_message_fee(&self) -> Option<&SetMessageFee<'_>> {
         match self {
             CoreBridgeDecree::SetMessageFee(inner) => Some(inner),
             _ => None,
@@ -125,7 +125,7 @@ impl<'a> CoreBridgeDecree<'a> {
         }
     }
 
-    pub fn transfer_fees(&self) -> Option<&TransferFees> {
+    pub fn transfer_fees(&self) -> Option<&TransferFees<'_>> {
         match self {
             CoreBridgeDecree::TransferFees(inner) => Some(inner),
             _ => None,
@@ -139,7 +139,7 @@ impl<'a> CoreBridgeDecree<'a> {
         }
     }
 
-    pub fn recover_chain_id(&self) -> Option<&RecoverChainId> {
+    pub fn recover_chain_id(&self) -> Option<&RecoverChainId<'_>> {
         match self {
             CoreBridgeDecree::RecoverChainId(inner) => Some(inner),
             _ => None,
@@ -617,4 +617,15 @@ mod test {
             .unwrap();
         assert_eq!(err, "Invalid Core Bridge governance message");
     }
+
+    #[test]
+    fn short_core_bridge_gov() {
+        let err = CoreBridgeGovPayload::parse(&super::GOV_MODULE[..31])
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            "CoreBridgeGovPayload span too short. Need at least 32 bytes"
+        );
+    }
 }
@@ -2,6 +2,7 @@ use crate::Payload;
 
 /// The non-type-flag contents
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum LiquidityLayerDepositMessage<'a> {
     Fill(Fill<'a>),
     SlowOrderResponse(SlowOrderResponse<'a>),
@@ -29,7 +30,7 @@ impl<'a> LiquidityLayerDepositMessage<'a> {
         self.as_ref()
     }
 
-    pub fn fill(&self) -> Option<&Fill> {
+    pub fn fill(&self) -> Option<&Fill<'_>> {
         match self {
             Self::Fill(inner) => Some(inner),
             _ => None,
@@ -45,7 +46,7 @@ impl<'a> LiquidityLayerDepositMessage<'a> {
         }
     }
 
-    pub fn slow_order_response(&self) -> Option<&SlowOrderResponse> {
+    pub fn slow_order_response(&self) -> Option<&SlowOrderResponse<'_>> {
         match self {
             Self::SlowOrderResponse(inner) => Some(inner),
             _ => None,
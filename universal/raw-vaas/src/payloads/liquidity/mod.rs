@@ -4,10 +4,14 @@ pub use deposit::*;
 use crate::{cctp::Deposit, Payload};
 
 /// The non-type-flag contents
+///
+/// New message types may be added as the liquidity layer grows.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum LiquidityLayerMessage<'a> {
     Deposit(Deposit<'a>),
     FastMarketOrder(FastMarketOrder<'a>),
+    FastFill(FastFill<'a>),
 }
 
 impl<'a> TryFrom<Payload<'a>> for LiquidityLayerMessage<'a> {
@@ -23,6 +27,7 @@ impl AsRef<[u8]> for LiquidityLayerMessage<'_> {
         match self {
             Self::Deposit(inner) => inner.as_ref(),
             Self::FastMarketOrder(inner) => inner.as_ref(),
+            Self::FastFill(inner) => inner.as_ref(),
         }
     }
 }
@@ -32,7 +37,7 @@ impl<'a> LiquidityLayerMessage<'a> {
         self.as_ref()
     }
 
-    pub fn deposit(&self) -> Option<&Deposit> {
+    pub fn deposit(&self) -> Option<&Deposit<'_>> {
         match self {
             Self::Deposit(inner) => Some(inner),
             _ => None,
@@ -48,7 +53,7 @@ impl<'a> LiquidityLayerMessage<'a> {
         }
     }
 
-    pub fn fast_market_order(&self) -> Option<&FastMarketOrder> {
+    pub fn fast_market_order(&self) -> Option<&FastMarketOrder<'_>> {
         match self {
             Self::FastMarketOrder(inner) => Some(inner),
             _ => None,
@@ -64,6 +69,22 @@ impl<'a> LiquidityLayerMessage<'a> {
         }
     }
 
+    pub fn fast_fill(&self) -> Option<&FastFill<'_>> {
+        match self {
+            Self::FastFill(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn to_fast_fill_unchecked(self) -> FastFill<'a> {
+        match self {
+            Self::FastFill(inner) => inner,
+            // The purpose of using this method is knowing that the enum variant is FastFill.
+            #[allow(clippy::panic)]
+            _ => panic!("LiquidityLayerMessage is not FastFill"),
+        }
+    }
+
     pub fn parse(span: &'a [u8]) -> Result<Self, &'static str> {
         if span.is_empty() {
             return Err("LiquidityLayerMessage span too short. Need at least 1 byte");
@@ -72,6 +93,7 @@ impl<'a> LiquidityLayerMessage<'a> {
         match span[0] {
             1 => Ok(Self::Deposit(Deposit::parse(&span[1..])?)),
             11 => Ok(Self::FastMarketOrder(FastMarketOrder::parse(&span[1..])?)),
+            12 => Ok(Self::FastFill(FastFill::parse(&span[1..])?)),
             _ => Err("Unknown LiquidityLayerMessage type"),
         }
     }
@@ -148,3 +170,53 @@ impl<'a> FastMarketOrder<'a> {
         Ok(fast_market_order)
     }
 }
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FastFill<'a>(&'a [u8]);
+
+impl AsRef<[u8]> for FastFill<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl<'a> FastFill<'a> {
+    pub fn fill_amount(&self) -> u64 {
+        u64::from_be_bytes(self.0[..8].try_into().unwrap())
+    }
+
+    pub fn source_chain(&self) -> u16 {
+        u16::from_be_bytes(self.0[8..10].try_into().unwrap())
+    }
+
+    pub fn order_sender(&self) -> [u8; 32] {
+        self.0[10..42].try_into().unwrap()
+    }
+
+    pub fn redeemer(&self) -> [u8; 32] {
+        self.0[42..74].try_into().unwrap()
+    }
+
+    pub fn redeemer_message_len(&self) -> u16 {
+        u16::from_be_bytes(self.0[74..76].try_into().unwrap())
+    }
+
+    pub fn redeemer_message(&'a self) -> Payload<'a> {
+        Payload::parse(&self.0[76..])
+    }
+
+    pub fn parse(span: &'a [u8]) -> Result<Self, &'static str> {
+        if span.len() < 76 {
+            return Err("FastFill span too short. Need at least 76 bytes");
+        }
+
+        let fast_fill = Self(span);
+
+        // Check payload length vs actual payload.
+        if fast_fill.redeemer_message().len() != usize::from(fast_fill.redeemer_message_len()) {
+            return Err("FastFill payload length mismatch");
+        }
+
+        Ok(fast_fill)
+    }
+}
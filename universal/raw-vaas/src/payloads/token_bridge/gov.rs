@@ -34,8 +34,8 @@ impl<'a> TokenBridgeGovPayload<'a> {
     }
 
     pub fn parse(span: &'a [u8]) -> Result<Self, &'static str> {
-        if span.is_empty() {
-            return Err("TokenBridgeGovPayload span too short. Need at least 1 byte");
+        if span.len() < 32 {
+            return Err("TokenBridgeGovPayload span too short. Need at least 32 bytes");
         }
 
         if &span[..32] != GOV_MODULE {
@@ -79,7 +79,7 @@ impl<'a> TokenBridgeDecree<'a> {
         self.as_ref()
     }
 
-    pub fn register_chain(&self) -> Option<&RegisterChain> {
+    pub fn register_chain(&self) -> Option<&RegisterChain<'_>> {
         match self {
             TokenBridgeDecree::RegisterChain(inner) => Some(inner),
             _ => None,
@@ -93,7 +93,7 @@ impl<'a> TokenBridgeDecree<'a> {
         }
     }
 
-    pub fn contract_upgrade(&self) -> Option<&ContractUpgrade> {
+    pub fn contract_upgrade(&self) -> Option<&ContractUpgrade<'_>> {
         match self {
             TokenBridgeDecree::ContractUpgrade(inner) => Some(inner),
             _ => None,
@@ -107,7 +107,7 @@ impl<'a> TokenBridgeDecree<'a> {
         }
     }
 
-    pub fn recover_chain_id(&self) -> Option<&RecoverChainId> {
+    pub fn recover_chain_id(&self) -> Option<&RecoverChainId<'_>> {
         match self {
             TokenBridgeDecree::RecoverChainId(inner) => Some(inner),
             _ => None,
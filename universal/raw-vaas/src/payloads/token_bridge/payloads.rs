@@ -75,7 +75,7 @@ impl<'a> TokenBridgeMessage<'a> {
         self.as_ref()
     }
 
-    pub fn transfer(&self) -> Option<&Transfer> {
+    pub fn transfer(&self) -> Option<&Transfer<'_>> {
         match self {
             TokenBridgeMessage::Transfer(inner) => Some(inner),
             _ => None,
@@ -89,7 +89,7 @@ impl<'a> TokenBridgeMessage<'a> {
         }
     }
 
-    pub fn attestation(&self) -> Option<&Attestation> {
+    pub fn attestation(&self) -> Option<&Attestation<'_>> {
         match self {
             TokenBridgeMessage::Attestation(inner) => Some(inner),
             _ => None,
@@ -103,7 +103,7 @@ impl<'a> TokenBridgeMessage<'a> {
         }
     }
 
-    pub fn transfer_with_message(&self) -> Option<&TransferWithMessage> {
+    pub fn transfer_with_message(&self) -> Option<&TransferWithMessage<'_>> {
         match self {
             TokenBridgeMessage::TransferWithMessage(inner) => Some(inner),
             _ => None,
@@ -172,6 +172,9 @@ impl<'a> Transfer<'a> {
         if span.len() < 132 {
             return Err("Transfer span too short. Need exactly 132 bytes");
         }
+        if span.len() > 132 {
+            return Err("Transfer span has trailing bytes. Need exactly 132 bytes");
+        }
 
         Ok(Self(&span[..132]))
     }
@@ -230,6 +233,9 @@ impl<'a> Attestation<'a> {
         if span.len() < 99 {
             return Err("Attestation span too short. Need exactly 99 bytes");
         }
+        if span.len() > 99 {
+            return Err("Attestation span has trailing bytes. Need exactly 99 bytes");
+        }
 
         Ok(Self(&span[..99]))
     }
@@ -278,7 +284,7 @@ impl<'a> TransferWithMessage<'a> {
         self.0[100..132].try_into().unwrap()
     }
 
-    pub fn payload(&'a self) -> Payload<'a> {
+    pub fn payload(&self) -> Payload<'a> {
         Payload::parse(&self.0[132..])
     }
 
@@ -293,7 +299,10 @@ impl<'a> TransferWithMessage<'a> {
 
 #[cfg(test)]
 mod test {
-    use crate::{token_bridge::TokenBridgePayload, Vaa};
+    use crate::{
+        token_bridge::{TokenBridgeMessage, TokenBridgePayload},
+        Vaa,
+    };
     use hex_literal::hex;
 
     #[test]
@@ -321,4 +330,34 @@ mod test {
         assert_eq!(attestation.symbol(), "WETH");
         assert_eq!(attestation.name(), "Wrapped ether");
     }
+
+    #[test]
+    fn attestation_trailing_bytes() {
+        let mut payload = hex!("02000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc200021257455448000000000000000000000000000000000000000000000000000000005772617070656420657468657200000000000000000000000000000000000000").to_vec();
+        assert!(TokenBridgeMessage::parse(&payload).is_ok());
+
+        payload.push(0);
+        let err = TokenBridgeMessage::parse(&payload).err().unwrap();
+        assert_eq!(
+            err,
+            "Attestation span has trailing bytes. Need exactly 99 bytes"
+        );
+
+        payload.truncate(99);
+        let err = TokenBridgeMessage::parse(&payload).err().unwrap();
+        assert_eq!(err, "Attestation span too short. Need exactly 99 bytes");
+    }
+
+    #[test]
+    fn transfer_trailing_bytes() {
+        let mut payload = vec![1; 133];
+        assert!(TokenBridgeMessage::parse(&payload).is_ok());
+
+        payload.push(0);
+        let err = TokenBridgeMessage::parse(&payload).err().unwrap();
+        assert_eq!(
+            err,
+            "Transfer span has trailing bytes. Need exactly 132 bytes"
+        );
+    }
 }
@@ -36,6 +36,10 @@ impl<'a> Vaa<'a> {
         self.header.signatures()
     }
 
+    pub fn header(&self) -> Header<'a> {
+        self.header
+    }
+
     pub fn body(&self) -> Body<'a> {
         self.body
     }
@@ -181,6 +185,11 @@ impl<'a> Payload<'a> {
         Self(span)
     }
 
+    /// The payload bytes, borrowed for as long as the underlying buffer.
+    pub fn span(&self) -> &'a [u8] {
+        self.0
+    }
+
     pub fn len(&self) -> usize {
         self.0.len()
     }
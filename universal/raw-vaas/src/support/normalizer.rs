@@ -0,0 +1,229 @@
+use std::fmt;
+
+use ruint::Uint;
+
+use super::U256;
+
+/// Token bridge transfers encode amounts with at most this many decimals.
+pub const MAX_DECIMALS: u8 = 8;
+
+const TEN: U256 = Uint::from_limbs([10, 0, 0, 0]);
+
+/// `10^power`, or `None` if it does not fit in 32 bytes.
+fn checked_pow10(power: u8) -> Option<U256> {
+    TEN.checked_pow(U256::from(Uint::<8, 1>::from(power)))
+}
+
+/// Scale factor between an asset's decimals and the encoded decimals.
+fn scale(decimals: u8) -> Option<U256> {
+    checked_pow10(decimals.saturating_sub(MAX_DECIMALS))
+}
+
+/// Converts token amounts from the decimals of the asset on the source chain to the encoded
+/// amount of a token bridge transfer, and from the encoded amount to the decimals of the
+/// asset on the destination chain.
+///
+/// Encoding truncates amounts of assets with more than [`MAX_DECIMALS`] decimals. The
+/// truncated remainder is the dust, which stays with the sender.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Normalizer {
+    pub source_decimals: u8,
+    pub target_decimals: u8,
+}
+
+/// An amount split into its encoded value and the dust lost to truncation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalized {
+    /// The amount with at most [`MAX_DECIMALS`] decimals.
+    pub encoded: U256,
+    /// What is left of the raw amount after truncation, in source decimals.
+    pub dust: U256,
+}
+
+/// The encoded amount and relayer fee of a transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NormalizedTransfer {
+    pub amount: Normalized,
+    pub relayer_fee: Normalized,
+}
+
+/// Error returned when amounts cannot be normalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizeError {
+    /// The relayer fee is larger than the transferred amount.
+    FeeExceedsAmount,
+    /// The encoded amount is zero after truncation.
+    ZeroAmount,
+}
+
+impl fmt::Display for NormalizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FeeExceedsAmount => write!(f, "relayer fee exceeds amount"),
+            Self::ZeroAmount => write!(f, "amount is zero after normalization"),
+        }
+    }
+}
+
+impl std::error::Error for NormalizeError {}
+
+impl Normalizer {
+    pub const fn new(source_decimals: u8, target_decimals: u8) -> Self {
+        Self {
+            source_decimals,
+            target_decimals,
+        }
+    }
+
+    /// Normalizer for an asset redeemed as a wrapped asset whose decimals are capped at
+    /// `max_wrapped_decimals`, e.g. `WRAPPED_MINT_MAX_DECIMALS` on Solana.
+    pub const fn wrapped(source_decimals: u8, max_wrapped_decimals: u8) -> Self {
+        Self::new(
+            source_decimals,
+            wrapped_decimals(source_decimals, max_wrapped_decimals),
+        )
+    }
+
+    /// Truncate a raw amount in source decimals to the encoded amount.
+    pub fn normalize(&self, amount: U256) -> Normalized {
+        match scale(self.source_decimals) {
+            Some(scale) => {
+                let (encoded, dust) = amount.div_rem(scale);
+                Normalized { encoded, dust }
+            }
+            // No amount is large enough to survive the truncation.
+            None => Normalized {
+                encoded: U256::ZERO,
+                dust: amount,
+            },
+        }
+    }
+
+    /// Normalize a transfer's amount and relayer fee, both in source decimals.
+    pub fn normalize_transfer(
+        &self,
+        amount: U256,
+        relayer_fee: U256,
+    ) -> Result<NormalizedTransfer, NormalizeError> {
+        if relayer_fee > amount {
+            return Err(NormalizeError::FeeExceedsAmount);
+        }
+
+        let amount = self.normalize(amount);
+        if amount.encoded == U256::ZERO {
+            return Err(NormalizeError::ZeroAmount);
+        }
+        Ok(NormalizedTransfer {
+            amount,
+            relayer_fee: self.normalize(relayer_fee),
+        })
+    }
+
+    /// Scale an encoded amount to target decimals. Returns `None` if the result overflows 32
+    /// bytes.
+    pub fn denormalize(&self, encoded: U256) -> Option<U256> {
+        scale(self.target_decimals).and_then(|scale| encoded.checked_mul(scale))
+    }
+
+    /// Scale an encoded amount to target decimals, wrapping on overflow.
+    pub fn wrapping_denormalize(&self, encoded: U256) -> U256 {
+        let scale = TEN.wrapping_pow(U256::from(Uint::<8, 1>::from(
+            self.target_decimals.saturating_sub(MAX_DECIMALS),
+        )));
+        encoded.wrapping_mul(scale)
+    }
+
+    /// The raw amount in source decimals which the encoded amount represents, i.e. the amount
+    /// actually transferred once dust is removed.
+    pub fn source_amount(&self, encoded: U256) -> Option<U256> {
+        Self::new(self.target_decimals, self.source_decimals).denormalize(encoded)
+    }
+}
+
+/// Decimals of a wrapped asset for an asset with `decimals`, on a chain which caps wrapped
+/// assets at `max_wrapped_decimals`.
+pub const fn wrapped_decimals(decimals: u8, max_wrapped_decimals: u8) -> u8 {
+    if decimals > max_wrapped_decimals {
+        max_wrapped_decimals
+    } else {
+        decimals
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dust() {
+        let normalizer = Normalizer::new(18, 18);
+        let amount = U256::from(1_234_567_891_234_567_891u64);
+
+        let normalized = normalizer.normalize(amount);
+        assert_eq!(normalized.encoded, U256::from(123_456_789u64));
+        assert_eq!(normalized.dust, U256::from(1_234_567_891u64));
+        assert_eq!(
+            normalizer.denormalize(normalized.encoded).unwrap() + normalized.dust,
+            amount
+        );
+
+        // Nothing is truncated at or below 8 decimals.
+        let normalized = Normalizer::new(6, 6).normalize(amount);
+        assert_eq!(normalized.encoded, amount);
+        assert_eq!(normalized.dust, U256::ZERO);
+
+        // Overflowing scale truncates everything.
+        let normalized = Normalizer::new(u8::MAX, 8).normalize(amount);
+        assert_eq!(normalized.encoded, U256::ZERO);
+        assert_eq!(normalized.dust, amount);
+    }
+
+    #[test]
+    fn wrapped() {
+        let normalizer = Normalizer::wrapped(18, 8);
+        assert_eq!(normalizer, Normalizer::new(18, 8));
+
+        let normalized = normalizer.normalize(U256::from(10u64).pow(U256::from(18)));
+        assert_eq!(
+            normalizer.denormalize(normalized.encoded),
+            Some(U256::from(100_000_000u64))
+        );
+        assert_eq!(
+            normalizer.source_amount(normalized.encoded),
+            Some(U256::from(10u64).pow(U256::from(18)))
+        );
+
+        assert_eq!(Normalizer::wrapped(6, 8), Normalizer::new(6, 6));
+    }
+
+    #[test]
+    fn transfer() {
+        let normalizer = Normalizer::new(9, 9);
+        assert_eq!(
+            normalizer.normalize_transfer(U256::from(100), U256::from(101)),
+            Err(NormalizeError::FeeExceedsAmount)
+        );
+        assert_eq!(
+            normalizer.normalize_transfer(U256::from(9), U256::ZERO),
+            Err(NormalizeError::ZeroAmount)
+        );
+
+        let transfer = normalizer
+            .normalize_transfer(U256::from(105), U256::from(21))
+            .unwrap();
+        assert_eq!(transfer.amount.encoded, U256::from(10));
+        assert_eq!(transfer.amount.dust, U256::from(5));
+        assert_eq!(transfer.relayer_fee.encoded, U256::from(2));
+        assert_eq!(transfer.relayer_fee.dust, U256::from(1));
+    }
+
+    #[test]
+    fn denormalize_overflow() {
+        let normalizer = Normalizer::new(18, 18);
+        assert_eq!(normalizer.denormalize(U256::MAX), None);
+        assert_eq!(
+            normalizer.wrapping_denormalize(U256::MAX),
+            U256::MAX.wrapping_mul(U256::from(10_000_000_000u64))
+        );
+    }
+}
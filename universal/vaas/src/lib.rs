@@ -8,8 +8,14 @@ pub mod utils;
 
 pub use protocol::{
     encoded_types::EncodedAmount,
+    message_id::{MessageId, ParseMessageIdError},
     signature::GuardianSetSig,
     vaa::{MessageHash, Vaa, VaaBody, VaaHash, VaaHeader},
 };
 pub use utils::{keccak256, quorum};
 pub use wormhole_io::{Readable, TypePrefixedPayload, Writeable};
+
+#[cfg(feature = "k256")]
+pub use k256;
+#[cfg(feature = "k256")]
+pub use protocol::signing::{guardian_address, TooManyGuardiansError};
@@ -0,0 +1,218 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use wormhole_io::deploys::ChainId;
+
+use crate::{
+    payloads::{
+        gov::{GovernanceDecree, GovernanceMessage, GOVERNANCE_CHAIN, GOVERNANCE_EMITTER},
+        PayloadKind,
+    },
+    TypePrefixedPayload, VaaBody,
+};
+
+/// Builds a complete governance VAA for a decree.
+///
+/// The module, emitter chain and emitter address are filled in from the decree. Unless
+/// overridden, the timestamp is the current time, the sequence and nonce are fresh values
+/// which do not repeat within the process, and the guardian set index is zero.
+///
+/// ```
+/// use wormhole_vaas::payloads::{gov::{GovernanceVaaBuilder, RegisterChain}, KnownPayload};
+/// use wormhole_vaas::io::deploys::{ChainId, KnownChainId};
+///
+/// let body = GovernanceVaaBuilder::new(RegisterChain::new(
+///     ChainId::Known(KnownChainId::Sepolia),
+///     [1; 32],
+/// ))
+/// .guardian_set_index(4)
+/// .body();
+/// assert!(matches!(body.known_payload(), KnownPayload::TokenBridgeGovernance(_)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct GovernanceVaaBuilder<P> {
+    message: GovernanceMessage<P>,
+    guardian_set_index: u32,
+    timestamp: u32,
+    nonce: u32,
+    sequence: u64,
+    consistency_level: u8,
+}
+
+impl<P: GovernanceDecree> GovernanceVaaBuilder<P> {
+    pub fn new(decree: P) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let sequence = fresh_sequence(now.as_nanos() as u64);
+
+        Self {
+            message: GovernanceMessage::new(decree),
+            guardian_set_index: 0,
+            timestamp: now.as_secs() as u32,
+            nonce: sequence as u32,
+            sequence,
+            consistency_level: 32,
+        }
+    }
+
+    pub fn guardian_set_index(mut self, guardian_set_index: u32) -> Self {
+        self.guardian_set_index = guardian_set_index;
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: u32) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    pub fn nonce(mut self, nonce: u32) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    pub fn sequence(mut self, sequence: u64) -> Self {
+        self.sequence = sequence;
+        self
+    }
+
+    pub fn message(&self) -> &GovernanceMessage<P> {
+        &self.message
+    }
+
+    /// The unsigned VAA body.
+    pub fn body(&self) -> VaaBody {
+        VaaBody {
+            timestamp: self.timestamp,
+            nonce: self.nonce,
+            emitter_chain: ChainId::from_u16(GOVERNANCE_CHAIN),
+            emitter_address: GOVERNANCE_EMITTER,
+            sequence: self.sequence,
+            consistency_level: self.consistency_level,
+            payload: PayloadKind::Binary(self.message.to_payload_vec()),
+        }
+    }
+
+    /// Sign the VAA with the guardian keys, in guardian set order.
+    #[cfg(feature = "k256")]
+    pub fn sign(
+        &self,
+        guardians: &[k256::ecdsa::SigningKey],
+    ) -> Result<crate::Vaa, crate::TooManyGuardiansError> {
+        self.body().sign(self.guardian_set_index, guardians)
+    }
+}
+
+/// A sequence derived from the current time, bumped if needed so it is never reused.
+fn fresh_sequence(now: u64) -> u64 {
+    static LAST: AtomicU64 = AtomicU64::new(0);
+
+    let mut last = LAST.load(Ordering::Relaxed);
+    loop {
+        let next = now.max(last + 1);
+        match LAST.compare_exchange_weak(last, next, Ordering::Relaxed, Ordering::Relaxed) {
+            Ok(_) => return next,
+            Err(actual) => last = actual,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use wormhole_io::deploys::KnownChainId;
+
+    use super::*;
+    use crate::payloads::{
+        gov::{core_bridge, token_bridge, GuardianSetUpdate, RegisterChain},
+        KnownPayload,
+    };
+
+    #[test]
+    fn register_chain() {
+        let decree = RegisterChain::new(ChainId::Known(KnownChainId::Sepolia), [1; 32]);
+        let builder = GovernanceVaaBuilder::new(decree.clone());
+        let body = builder.body();
+
+        assert_eq!(body.emitter_chain, GOVERNANCE_CHAIN);
+        assert_eq!(body.emitter_address, GOVERNANCE_EMITTER);
+        assert_eq!(
+            body.known_payload(),
+            KnownPayload::TokenBridgeGovernance(GovernanceMessage {
+                header: builder.message().header,
+                decree: token_bridge::Decree::RegisterChain(decree),
+            })
+        );
+        assert_eq!(
+            builder.message().header.module,
+            token_bridge::GOVERNANCE_MODULE
+        );
+
+        // Each builder gets a fresh sequence.
+        let other = GovernanceVaaBuilder::new(RegisterChain::new(
+            ChainId::Known(KnownChainId::Sepolia),
+            [1; 32],
+        ));
+        assert_ne!(other.body().sequence, body.sequence);
+        assert_ne!(other.body().double_digest(), body.double_digest());
+    }
+
+    #[test]
+    fn overrides() {
+        let body = GovernanceVaaBuilder::new(GuardianSetUpdate::new(1, vec![[2; 20]]))
+            .timestamp(3)
+            .nonce(4)
+            .sequence(5)
+            .body();
+
+        assert_eq!((body.timestamp, body.nonce, body.sequence), (3, 4, 5));
+        let message = body
+            .read_payload::<GovernanceMessage<core_bridge::Decree>>()
+            .unwrap();
+        assert_eq!(message.header.module, core_bridge::GOVERNANCE_MODULE);
+        assert_eq!(
+            message.decree,
+            core_bridge::Decree::GuardianSetUpdate(GuardianSetUpdate::new(1, vec![[2; 20]]))
+        );
+    }
+
+    #[cfg(feature = "k256")]
+    #[test]
+    fn sign() {
+        use k256::ecdsa::SigningKey;
+
+        use crate::{guardian_address, Readable, Vaa, Writeable};
+
+        let guardians: Vec<_> = (1..=3u8)
+            .map(|i| SigningKey::from_slice(&[i; 32]).unwrap())
+            .collect();
+        let vaa = GovernanceVaaBuilder::new(GuardianSetUpdate::new(
+            5,
+            guardians
+                .iter()
+                .map(|key| guardian_address(key.verifying_key()))
+                .collect(),
+        ))
+        .guardian_set_index(4)
+        .sign(&guardians)
+        .unwrap();
+
+        let vaa = Vaa::read(&mut vaa.to_vec().as_slice()).unwrap();
+        assert_eq!(vaa.header.guardian_set_index, 4);
+        assert_eq!(vaa.header.signatures.len(), 3);
+
+        let digest = vaa.body.double_digest();
+        for (sig, key) in vaa.header.signatures.iter().zip(&guardians) {
+            assert_eq!(
+                sig.recover(&digest),
+                Some(guardian_address(key.verifying_key()))
+            );
+        }
+
+        let too_many = vec![guardians[0].clone(); 257];
+        assert!(GovernanceVaaBuilder::new(GuardianSetUpdate::new(5, vec![]))
+            .sign(&too_many)
+            .is_err());
+    }
+}
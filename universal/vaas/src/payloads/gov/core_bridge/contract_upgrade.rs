@@ -1,10 +1,17 @@
 use wormhole_io::deploys::ChainId;
 
-use crate::{Readable, TypePrefixedPayload, Writeable};
+use crate::{payloads::gov::GovernanceDecree, Readable, TypePrefixedPayload, Writeable};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractUpgrade {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub chain: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub implementation: [u8; 32],
 }
 
@@ -12,6 +19,14 @@ impl TypePrefixedPayload for ContractUpgrade {
     const TYPE: &[u8] = &[1];
 }
 
+impl GovernanceDecree for ContractUpgrade {
+    const MODULE: [u8; 32] = super::GOVERNANCE_MODULE;
+
+    fn target_chain(&self) -> ChainId {
+        self.chain
+    }
+}
+
 impl Readable for ContractUpgrade {
     const SIZE: Option<usize> = Some(2 + 32);
 
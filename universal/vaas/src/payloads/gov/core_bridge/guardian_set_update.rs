@@ -1,18 +1,44 @@
 use io::WriteableSequence;
+use wormhole_io::deploys::{ChainId, KnownChainId};
 
-use crate::{Readable, TypePrefixedPayload, Writeable};
+use crate::{payloads::gov::GovernanceDecree, Readable, TypePrefixedPayload, Writeable};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct GuardianSetUpdate {
+    #[cfg_attr(feature = "serde", serde(skip))]
     _gap: [u8; 2], // This should never be encoded with anything.
     pub new_index: u32,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::support::serde::hex_array_seq")
+    )]
     pub guardians: WriteableSequence<u8, Vec<[u8; 20]>>,
 }
 
+impl GuardianSetUpdate {
+    pub fn new(new_index: u32, guardians: Vec<[u8; 20]>) -> Self {
+        Self {
+            _gap: Default::default(),
+            new_index,
+            guardians: guardians.into(),
+        }
+    }
+}
+
 impl TypePrefixedPayload for GuardianSetUpdate {
     const TYPE: &[u8] = &[2];
 }
 
+impl GovernanceDecree for GuardianSetUpdate {
+    const MODULE: [u8; 32] = super::GOVERNANCE_MODULE;
+
+    fn target_chain(&self) -> ChainId {
+        ChainId::Known(KnownChainId::Unset)
+    }
+}
+
 impl Readable for GuardianSetUpdate {
     const SIZE: Option<usize> = None;
 
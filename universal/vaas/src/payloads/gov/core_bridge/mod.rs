@@ -13,7 +13,9 @@ pub use set_message_fee::SetMessageFee;
 mod transfer_fees;
 pub use transfer_fees::TransferFees;
 
-use crate::{Readable, TypePrefixedPayload, Writeable};
+use wormhole_io::deploys::ChainId;
+
+use crate::{payloads::gov::GovernanceDecree, Readable, TypePrefixedPayload, Writeable};
 use hex_literal::hex;
 
 /// A.K.A. "Core".
@@ -21,6 +23,7 @@ pub const GOVERNANCE_MODULE: [u8; 32] =
     hex!("00000000000000000000000000000000000000000000000000000000436f7265");
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Decree {
     ContractUpgrade(ContractUpgrade),
     GuardianSetUpdate(GuardianSetUpdate),
@@ -33,6 +36,20 @@ impl TypePrefixedPayload for Decree {
     const TYPE: &[u8] = &[];
 }
 
+impl GovernanceDecree for Decree {
+    const MODULE: [u8; 32] = GOVERNANCE_MODULE;
+
+    fn target_chain(&self) -> ChainId {
+        match self {
+            Decree::ContractUpgrade(inner) => inner.target_chain(),
+            Decree::GuardianSetUpdate(inner) => inner.target_chain(),
+            Decree::SetMessageFee(inner) => inner.target_chain(),
+            Decree::TransferFees(inner) => inner.target_chain(),
+            Decree::RecoverChainId(inner) => inner.target_chain(),
+        }
+    }
+}
+
 impl Writeable for Decree {
     fn write<W>(&self, writer: &mut W) -> std::io::Result<()>
     where
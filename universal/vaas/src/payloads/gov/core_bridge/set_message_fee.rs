@@ -1,10 +1,19 @@
 use wormhole_io::deploys::ChainId;
 
-use crate::{utils::U256, Readable, TypePrefixedPayload, Writeable};
+use crate::{
+    payloads::gov::GovernanceDecree, utils::U256, Readable, TypePrefixedPayload, Writeable,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct SetMessageFee {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub chain: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::decimal"))]
     pub fee: U256,
 }
 
@@ -12,6 +21,14 @@ impl TypePrefixedPayload for SetMessageFee {
     const TYPE: &[u8] = &[3];
 }
 
+impl GovernanceDecree for SetMessageFee {
+    const MODULE: [u8; 32] = super::GOVERNANCE_MODULE;
+
+    fn target_chain(&self) -> ChainId {
+        self.chain
+    }
+}
+
 impl Readable for SetMessageFee {
     const SIZE: Option<usize> = Some(2 + 32);
 
@@ -1,11 +1,21 @@
 use wormhole_io::deploys::ChainId;
 
-use crate::{utils::U256, Readable, TypePrefixedPayload, Writeable};
+use crate::{
+    payloads::gov::GovernanceDecree, utils::U256, Readable, TypePrefixedPayload, Writeable,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TransferFees {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub chain: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::decimal"))]
     pub amount: U256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub recipient: [u8; 32],
 }
 
@@ -13,6 +23,14 @@ impl TypePrefixedPayload for TransferFees {
     const TYPE: &[u8] = &[4];
 }
 
+impl GovernanceDecree for TransferFees {
+    const MODULE: [u8; 32] = super::GOVERNANCE_MODULE;
+
+    fn target_chain(&self) -> ChainId {
+        self.chain
+    }
+}
+
 impl Readable for TransferFees {
     const SIZE: Option<usize> = Some(2 + 32 + 32);
 
@@ -6,7 +6,14 @@ pub use core_bridge::{
 pub mod token_bridge;
 pub use token_bridge::RegisterChain;
 
+mod builder;
+pub use builder::GovernanceVaaBuilder;
+
+mod validator;
+pub use validator::{GovernanceError, GovernanceValidator};
+
 use hex_literal::hex;
+use wormhole_io::deploys::ChainId;
 
 use crate::{Readable, TypePrefixedPayload, Writeable};
 
@@ -29,7 +36,10 @@ pub const GOVERNANCE_EMITTER: [u8; 32] =
 ///
 /// [specification]: https://docs.wormhole.com/wormhole/explore-wormhole/vaa#governance
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct GovernanceHeader {
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub module: [u8; 32],
 }
 
@@ -60,11 +70,34 @@ impl Writeable for GovernanceHeader {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct GovernanceMessage<P> {
     pub header: GovernanceHeader,
     pub decree: P,
 }
 
+impl<P: GovernanceDecree> GovernanceMessage<P> {
+    /// Wrap the decree with the header for its governance module.
+    pub fn new(decree: P) -> Self {
+        Self {
+            header: GovernanceHeader { module: P::MODULE },
+            decree,
+        }
+    }
+}
+
+/// A governance decree, which knows the module it belongs to and the chain it targets.
+pub trait GovernanceDecree: TypePrefixedPayload {
+    /// The module the decree is addressed to, e.g. [`core_bridge::GOVERNANCE_MODULE`].
+    const MODULE: [u8; 32];
+
+    /// The chain the decree applies to. [`KnownChainId::Unset`] (zero) means every chain.
+    ///
+    /// [`KnownChainId::Unset`]: wormhole_io::deploys::KnownChainId::Unset
+    fn target_chain(&self) -> ChainId;
+}
+
 impl<P: TypePrefixedPayload> TypePrefixedPayload for GovernanceMessage<P> {
     const TYPE: &[u8] = &[];
 }
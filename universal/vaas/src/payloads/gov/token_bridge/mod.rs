@@ -7,7 +7,9 @@ pub use recover_chain_id::RecoverChainId;
 mod contract_upgrade;
 pub use contract_upgrade::ContractUpgrade;
 
-use crate::{Readable, TypePrefixedPayload, Writeable};
+use wormhole_io::deploys::ChainId;
+
+use crate::{payloads::gov::GovernanceDecree, Readable, TypePrefixedPayload, Writeable};
 use hex_literal::hex;
 
 /// A.K.A. "TokenBridge".
@@ -15,6 +17,7 @@ pub const GOVERNANCE_MODULE: [u8; 32] =
     hex!("000000000000000000000000000000000000000000546f6b656e427269646765");
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Decree {
     RegisterChain(RegisterChain),
     ContractUpgrade(ContractUpgrade),
@@ -25,6 +28,18 @@ impl TypePrefixedPayload for Decree {
     const TYPE: &[u8] = &[];
 }
 
+impl GovernanceDecree for Decree {
+    const MODULE: [u8; 32] = GOVERNANCE_MODULE;
+
+    fn target_chain(&self) -> ChainId {
+        match self {
+            Decree::RegisterChain(inner) => inner.target_chain(),
+            Decree::ContractUpgrade(inner) => inner.target_chain(),
+            Decree::RecoverChainId(inner) => inner.target_chain(),
+        }
+    }
+}
+
 impl Writeable for Decree {
     fn write<W>(&self, writer: &mut W) -> std::io::Result<()>
     where
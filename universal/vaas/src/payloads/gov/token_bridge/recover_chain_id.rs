@@ -1,18 +1,50 @@
-use wormhole_io::deploys::ChainId;
+use wormhole_io::deploys::{evm_chain_id, ChainId, EvmChain};
 
-use crate::{utils::U256, Readable, TypePrefixedPayload, Writeable};
+use crate::{
+    payloads::gov::GovernanceDecree, utils::U256, Readable, TypePrefixedPayload, Writeable,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct RecoverChainId {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub recovered_chain: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::decimal"))]
     pub evm_chain_id: U256,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub new_chain: ChainId,
 }
 
+impl RecoverChainId {
+    /// The known EVM chain whose EIP-155 chain id is [`evm_chain_id`](Self::evm_chain_id), if
+    /// any. A decree for a known chain is consistent if that chain is
+    /// [`new_chain`](Self::new_chain).
+    pub fn known_evm_chain(&self) -> Option<EvmChain> {
+        u64::try_from(self.evm_chain_id)
+            .ok()
+            .and_then(evm_chain_id::from_evm_chain_id)
+    }
+}
+
 impl TypePrefixedPayload for RecoverChainId {
     const TYPE: &[u8] = &[3];
 }
 
+impl GovernanceDecree for RecoverChainId {
+    const MODULE: [u8; 32] = super::GOVERNANCE_MODULE;
+
+    fn target_chain(&self) -> ChainId {
+        self.recovered_chain
+    }
+}
+
 impl Readable for RecoverChainId {
     const SIZE: Option<usize> = Some(2 + 32 + 2);
 
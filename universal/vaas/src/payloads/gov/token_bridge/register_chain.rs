@@ -1,8 +1,10 @@
-use wormhole_io::deploys::ChainId;
+use wormhole_io::deploys::{ChainId, KnownChainId};
 
-use crate::{Readable, TypePrefixedPayload, Writeable};
+use crate::{payloads::gov::GovernanceDecree, Readable, TypePrefixedPayload, Writeable};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct RegisterChain {
     /// This is a placeholder for the `chain` field in the
     /// [`GovernanceHeader`]. The `chain` field is never used for
@@ -10,15 +12,39 @@ pub struct RegisterChain {
     /// empty.
     ///
     /// [`Governanceheader`]: crate::payloads::gov::GovernanceHeader
+    #[cfg_attr(feature = "serde", serde(skip))]
     _gap: [u8; 2],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub foreign_chain: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub foreign_emitter: [u8; 32],
 }
 
+impl RegisterChain {
+    pub fn new(foreign_chain: ChainId, foreign_emitter: [u8; 32]) -> Self {
+        Self {
+            _gap: Default::default(),
+            foreign_chain,
+            foreign_emitter,
+        }
+    }
+}
+
 impl TypePrefixedPayload for RegisterChain {
     const TYPE: &[u8] = &[1];
 }
 
+impl GovernanceDecree for RegisterChain {
+    const MODULE: [u8; 32] = super::GOVERNANCE_MODULE;
+
+    fn target_chain(&self) -> ChainId {
+        ChainId::Known(KnownChainId::Unset)
+    }
+}
+
 impl Readable for RegisterChain {
     const SIZE: Option<usize> = Some(2 + 2 + 32);
 
@@ -0,0 +1,388 @@
+use std::fmt;
+
+use wormhole_io::deploys::{ChainId, KnownChainId};
+
+use crate::{
+    payloads::gov::{GovernanceDecree, GovernanceMessage, GOVERNANCE_CHAIN, GOVERNANCE_EMITTER},
+    utils, Vaa, VaaBody,
+};
+
+/// Why a governance VAA was rejected by a [`GovernanceValidator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GovernanceError {
+    /// The VAA was not emitted on [`GOVERNANCE_CHAIN`].
+    InvalidEmitterChain(ChainId),
+    /// The VAA was not emitted by [`GOVERNANCE_EMITTER`].
+    InvalidEmitterAddress([u8; 32]),
+    /// The payload is not a governance message for the expected decree type.
+    InvalidPayload,
+    /// The governance message is for a different module.
+    InvalidModule { expected: [u8; 32], found: [u8; 32] },
+    /// The decree targets a chain other than this one.
+    InvalidTargetChain(ChainId),
+    /// Governance must be signed by the current guardian set.
+    GuardianSetMismatch { expected: u32, found: u32 },
+    /// Too few guardians signed.
+    NoQuorum { signatures: usize, required: usize },
+    /// A signature's guardian index is out of range or not in strictly increasing order.
+    InvalidGuardianIndex(u8),
+    /// A signature was not produced by the guardian at its index.
+    InvalidSignature(u8),
+}
+
+impl fmt::Display for GovernanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidEmitterChain(chain) => {
+                write!(f, "governance emitted on chain {}", chain.to_u16())
+            }
+            Self::InvalidEmitterAddress(_) => write!(f, "not emitted by the governance emitter"),
+            Self::InvalidPayload => write!(f, "invalid governance payload"),
+            Self::InvalidModule { .. } => write!(f, "governance for another module"),
+            Self::InvalidTargetChain(chain) => {
+                write!(f, "governance targets chain {}", chain.to_u16())
+            }
+            Self::GuardianSetMismatch { expected, found } => write!(
+                f,
+                "signed by guardian set {found}, expected current guardian set {expected}"
+            ),
+            Self::NoQuorum {
+                signatures,
+                required,
+            } => write!(f, "{signatures} signatures, {required} required"),
+            Self::InvalidGuardianIndex(index) => write!(f, "invalid guardian index {index}"),
+            Self::InvalidSignature(index) => write!(f, "invalid signature for guardian {index}"),
+        }
+    }
+}
+
+impl std::error::Error for GovernanceError {}
+
+/// Applies the rules every consumer of governance VAAs must check.
+///
+/// A governance VAA is accepted if it was emitted by [`GOVERNANCE_EMITTER`] on
+/// [`GOVERNANCE_CHAIN`], is addressed to the decree's module and targets either every chain or
+/// the chain the validator was created for, and is signed by a quorum of the current guardian
+/// set.
+///
+/// Verifying the signatures themselves requires the `k256` feature, see
+/// [`validate`](Self::validate). Without it, [`check_structure`](Self::check_structure) checks
+/// everything but the signatures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GovernanceValidator {
+    chain: ChainId,
+    guardian_set_index: u32,
+    guardians: Vec<[u8; 20]>,
+}
+
+impl GovernanceValidator {
+    pub fn new(chain: ChainId, guardian_set_index: u32, guardians: Vec<[u8; 20]>) -> Self {
+        Self {
+            chain,
+            guardian_set_index,
+            guardians,
+        }
+    }
+
+    /// Validate a signed governance VAA, including its signatures, and decode its message.
+    #[cfg(feature = "k256")]
+    pub fn validate<D: GovernanceDecree + 'static>(
+        &self,
+        vaa: &Vaa,
+    ) -> Result<GovernanceMessage<D>, GovernanceError> {
+        let message = self.check_structure(vaa)?;
+
+        let digest = vaa.body.double_digest();
+        for sig in &vaa.header.signatures {
+            let index = sig.guardian_set_index;
+            if sig.recover(&digest) != Some(self.guardians[usize::from(index)]) {
+                return Err(GovernanceError::InvalidSignature(index));
+            }
+        }
+
+        Ok(message)
+    }
+
+    /// Validate everything but the signatures themselves: the body, the guardian set index, and
+    /// that the signatures reach quorum with valid, strictly increasing guardian indices.
+    ///
+    /// The signatures must be verified separately, e.g. by [`validate`](Self::validate) with
+    /// the `k256` feature or by the chain's core bridge.
+    pub fn check_structure<D: GovernanceDecree + 'static>(
+        &self,
+        vaa: &Vaa,
+    ) -> Result<GovernanceMessage<D>, GovernanceError> {
+        let message = self.validate_body(&vaa.body)?;
+
+        let found = vaa.header.guardian_set_index;
+        if found != self.guardian_set_index {
+            return Err(GovernanceError::GuardianSetMismatch {
+                expected: self.guardian_set_index,
+                found,
+            });
+        }
+        self.check_signers(vaa)?;
+
+        Ok(message)
+    }
+
+    /// Validate the emitter and payload of a governance VAA body, without any signatures.
+    pub fn validate_body<D: GovernanceDecree + 'static>(
+        &self,
+        body: &VaaBody,
+    ) -> Result<GovernanceMessage<D>, GovernanceError> {
+        if body.emitter_chain != GOVERNANCE_CHAIN {
+            return Err(GovernanceError::InvalidEmitterChain(body.emitter_chain));
+        }
+        if body.emitter_address != GOVERNANCE_EMITTER {
+            return Err(GovernanceError::InvalidEmitterAddress(body.emitter_address));
+        }
+
+        // Check the module before decoding, so that governance for other modules is reported as
+        // such rather than as an undecodable payload.
+        let payload = body.payload_bytes().unwrap_or_default();
+        if let Some(found) = payload.get(..32) {
+            let found: [u8; 32] = found.try_into().unwrap();
+            if found != D::MODULE {
+                return Err(GovernanceError::InvalidModule {
+                    expected: D::MODULE,
+                    found,
+                });
+            }
+        }
+
+        let message = body
+            .read_payload::<GovernanceMessage<D>>()
+            .ok_or(GovernanceError::InvalidPayload)?;
+        self.check_message(&message)?;
+        Ok(message)
+    }
+
+    /// Check that an already decoded message is for the decree's module and targets this chain.
+    pub fn check_message<D: GovernanceDecree>(
+        &self,
+        message: &GovernanceMessage<D>,
+    ) -> Result<(), GovernanceError> {
+        if message.header.module != D::MODULE {
+            return Err(GovernanceError::InvalidModule {
+                expected: D::MODULE,
+                found: message.header.module,
+            });
+        }
+
+        let target = message.decree.target_chain();
+        if target != ChainId::Known(KnownChainId::Unset) && target != self.chain {
+            return Err(GovernanceError::InvalidTargetChain(target));
+        }
+        Ok(())
+    }
+
+    fn check_signers(&self, vaa: &Vaa) -> Result<(), GovernanceError> {
+        let signatures = &vaa.header.signatures;
+        let required = utils::quorum(self.guardians.len());
+        if signatures.len() < required {
+            return Err(GovernanceError::NoQuorum {
+                signatures: signatures.len(),
+                required,
+            });
+        }
+
+        let mut next = 0;
+        for sig in signatures {
+            let index = sig.guardian_set_index;
+            if usize::from(index) < next || usize::from(index) >= self.guardians.len() {
+                return Err(GovernanceError::InvalidGuardianIndex(index));
+            }
+            next = usize::from(index) + 1;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::payloads::{
+        gov::{core_bridge, token_bridge, GovernanceVaaBuilder, RegisterChain, SetMessageFee},
+        PayloadKind,
+    };
+    use crate::{utils::U256, GuardianSetSig, VaaHeader};
+
+    const CHAIN: ChainId = ChainId::Known(KnownChainId::Sepolia);
+
+    fn validator() -> GovernanceValidator {
+        GovernanceValidator::new(CHAIN, 1, vec![[1; 20], [2; 20], [3; 20]])
+    }
+
+    fn unsigned(body: VaaBody, guardian_set_index: u32, signers: &[u8]) -> Vaa {
+        Vaa {
+            header: VaaHeader {
+                version: 1,
+                guardian_set_index,
+                signatures: signers
+                    .iter()
+                    .map(|&guardian_set_index| GuardianSetSig {
+                        guardian_set_index,
+                        signature: [0; 65],
+                    })
+                    .collect(),
+            },
+            body,
+        }
+    }
+
+    fn set_message_fee(chain: ChainId) -> VaaBody {
+        GovernanceVaaBuilder::new(SetMessageFee {
+            chain,
+            fee: U256::from(1),
+        })
+        .body()
+    }
+
+    #[test]
+    fn body() {
+        let validator = validator();
+
+        assert!(validator
+            .validate_body::<core_bridge::Decree>(&set_message_fee(CHAIN))
+            .is_ok());
+        assert!(validator
+            .validate_body::<SetMessageFee>(&set_message_fee(ChainId::Known(KnownChainId::Unset)))
+            .is_ok());
+        assert_eq!(
+            validator.validate_body::<core_bridge::Decree>(&set_message_fee(ChainId::Known(
+                KnownChainId::Ethereum
+            ))),
+            Err(GovernanceError::InvalidTargetChain(ChainId::Known(
+                KnownChainId::Ethereum
+            )))
+        );
+        assert_eq!(
+            validator.validate_body::<token_bridge::Decree>(&set_message_fee(CHAIN)),
+            Err(GovernanceError::InvalidModule {
+                expected: token_bridge::GOVERNANCE_MODULE,
+                found: core_bridge::GOVERNANCE_MODULE,
+            })
+        );
+
+        let mut body = set_message_fee(CHAIN);
+        body.emitter_chain = ChainId::Known(KnownChainId::Ethereum);
+        assert_eq!(
+            validator.validate_body::<core_bridge::Decree>(&body),
+            Err(GovernanceError::InvalidEmitterChain(body.emitter_chain))
+        );
+
+        let mut body = set_message_fee(CHAIN);
+        body.emitter_address = [5; 32];
+        assert_eq!(
+            validator.validate_body::<core_bridge::Decree>(&body),
+            Err(GovernanceError::InvalidEmitterAddress([5; 32]))
+        );
+
+        let mut body = set_message_fee(CHAIN);
+        body.payload = PayloadKind::Binary(core_bridge::GOVERNANCE_MODULE.to_vec());
+        assert_eq!(
+            validator.validate_body::<core_bridge::Decree>(&body),
+            Err(GovernanceError::InvalidPayload)
+        );
+    }
+
+    #[test]
+    fn guardian_set() {
+        let validator = validator();
+
+        assert_eq!(
+            validator.check_structure::<core_bridge::Decree>(&unsigned(
+                set_message_fee(CHAIN),
+                0,
+                &[]
+            )),
+            Err(GovernanceError::GuardianSetMismatch {
+                expected: 1,
+                found: 0
+            })
+        );
+        assert_eq!(
+            validator.check_structure::<core_bridge::Decree>(&unsigned(
+                set_message_fee(CHAIN),
+                1,
+                &[0]
+            )),
+            Err(GovernanceError::NoQuorum {
+                signatures: 1,
+                required: 3
+            })
+        );
+        assert_eq!(
+            validator.check_structure::<core_bridge::Decree>(&unsigned(
+                set_message_fee(CHAIN),
+                1,
+                &[0, 2, 1]
+            )),
+            Err(GovernanceError::InvalidGuardianIndex(1))
+        );
+        assert_eq!(
+            validator.check_structure::<core_bridge::Decree>(&unsigned(
+                set_message_fee(CHAIN),
+                1,
+                &[0, 1, 3]
+            )),
+            Err(GovernanceError::InvalidGuardianIndex(3))
+        );
+
+        // Governance for every module has to be signed by the current guardian set.
+        let register = GovernanceVaaBuilder::new(RegisterChain::new(
+            ChainId::Known(KnownChainId::Ethereum),
+            [1; 32],
+        ))
+        .body();
+        assert_eq!(
+            validator.check_structure::<token_bridge::Decree>(&unsigned(register.clone(), 0, &[])),
+            Err(GovernanceError::GuardianSetMismatch {
+                expected: 1,
+                found: 0
+            })
+        );
+        assert!(validator
+            .check_structure::<token_bridge::Decree>(&unsigned(register, 1, &[0, 1, 2]))
+            .is_ok());
+    }
+
+    #[cfg(feature = "k256")]
+    #[test]
+    fn signatures() {
+        use k256::ecdsa::SigningKey;
+
+        use crate::guardian_address;
+
+        let keys: Vec<_> = (1..=4u8)
+            .map(|i| SigningKey::from_slice(&[i; 32]).unwrap())
+            .collect();
+        let validator = GovernanceValidator::new(
+            CHAIN,
+            1,
+            keys.iter()
+                .map(|key| guardian_address(key.verifying_key()))
+                .collect(),
+        );
+
+        let builder = GovernanceVaaBuilder::new(SetMessageFee {
+            chain: CHAIN,
+            fee: U256::from(1),
+        })
+        .guardian_set_index(1);
+        assert!(validator
+            .validate::<core_bridge::Decree>(&builder.sign(&keys).unwrap())
+            .is_ok());
+
+        // Guardian 2 signs with guardian 3's key.
+        let mut vaa = builder.sign(&keys).unwrap();
+        vaa.header.signatures[2].signature = vaa.header.signatures[3].signature;
+        assert_eq!(
+            validator.validate::<core_bridge::Decree>(&vaa),
+            Err(GovernanceError::InvalidSignature(2))
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use wormhole_io::deploys::{deploys::find_emitters, ChainId, ContractRole};
+
+use crate::{
+    payloads::{
+        gov::{self, GovernanceMessage},
+        liquidity::{CctpDeposit, LiquidityLayerMessage},
+        token_bridge::TokenBridgeMessage,
+        Message,
+    },
+    Vaa, VaaBody,
+};
+
+/// A payload decoded according to who emitted it.
+///
+/// See [`KnownPayload::decode`] for how the decoder is chosen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum KnownPayload {
+    /// Core bridge governance, emitted by the governance emitter for the "Core" module.
+    CoreGovernance(GovernanceMessage<gov::core_bridge::Decree>),
+    /// Token bridge governance, emitted by the governance emitter for the "TokenBridge" module.
+    TokenBridgeGovernance(GovernanceMessage<gov::token_bridge::Decree>),
+    /// A transfer, attestation or transfer with payload emitted by a known token bridge.
+    TokenBridge(TokenBridgeMessage),
+    /// A CCTP deposit with payload emitted by a known Circle Integration contract.
+    Cctp(CctpDeposit),
+    /// A CCTP deposit, fast market order or fast fill emitted by a known token router.
+    LiquidityLayer(LiquidityLayerMessage),
+    /// A generic [`Message`] from any other emitter.
+    Message(Message),
+    /// Anything else, as raw bytes.
+    Unknown(
+        #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_bytes"))] Vec<u8>,
+    ),
+}
+
+impl KnownPayload {
+    /// Decode the payload of a VAA body.
+    ///
+    /// The decoder is chosen by the emitter rather than by the payload's leading byte:
+    /// - the governance emitter is decoded by its governance module;
+    /// - token bridge, Circle Integration and token router emitters listed in
+    ///   [`wormhole_io::deploys`] (on any network) are decoded as their respective messages;
+    /// - any other emitter is tried as a generic [`Message`].
+    ///
+    /// A payload which does not decode fully with the chosen decoder is returned as
+    /// [`KnownPayload::Unknown`].
+    pub fn decode(body: &VaaBody) -> Self {
+        Self::try_decode(body)
+            .unwrap_or_else(|| Self::Unknown(body.payload_bytes().unwrap_or_default().to_vec()))
+    }
+
+    fn try_decode(body: &VaaBody) -> Option<Self> {
+        let chain = body.emitter_chain;
+        let emitter = &body.emitter_address;
+
+        if chain == gov::GOVERNANCE_CHAIN && *emitter == gov::GOVERNANCE_EMITTER {
+            let module = body.payload_bytes()?.get(..32)?;
+            return if module == gov::core_bridge::GOVERNANCE_MODULE {
+                body.read_payload().map(Self::CoreGovernance)
+            } else if module == gov::token_bridge::GOVERNANCE_MODULE {
+                body.read_payload().map(Self::TokenBridgeGovernance)
+            } else {
+                None
+            };
+        }
+
+        if is_emitter(chain, emitter, ContractRole::TokenBridge) {
+            return body.read_payload().map(Self::TokenBridge);
+        }
+
+        if is_emitter(chain, emitter, ContractRole::CircleIntegration) {
+            return body.read_payload().map(Self::Cctp);
+        }
+
+        if is_emitter(chain, emitter, ContractRole::TokenRouter) {
+            return body.read_payload().map(Self::LiquidityLayer);
+        }
+
+        body.read_payload().map(Self::Message)
+    }
+}
+
+fn is_emitter(chain: ChainId, emitter: &[u8; 32], role: ContractRole) -> bool {
+    find_emitters(chain, emitter).any(|known| known.role == role)
+}
+
+impl VaaBody {
+    /// Decode the payload with [`KnownPayload::decode`].
+    pub fn known_payload(&self) -> KnownPayload {
+        KnownPayload::decode(self)
+    }
+}
+
+impl Vaa {
+    /// Decode the payload with [`KnownPayload::decode`].
+    pub fn known_payload(&self) -> KnownPayload {
+        self.body.known_payload()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use hex_literal::hex;
+    use wormhole_io::deploys::KnownChainId;
+
+    use super::*;
+    use crate::{
+        payloads::{gov::GovernanceHeader, liquidity::FastMarketOrder, PayloadKind},
+        utils::U256,
+        Readable, TypePrefixedPayload,
+    };
+
+    fn body(emitter_chain: KnownChainId, emitter_address: [u8; 32], payload: Vec<u8>) -> VaaBody {
+        VaaBody {
+            timestamp: 0,
+            nonce: 0,
+            emitter_chain: ChainId::Known(emitter_chain),
+            emitter_address,
+            sequence: 0,
+            consistency_level: 0,
+            payload: PayloadKind::Binary(payload),
+        }
+    }
+
+    #[test]
+    fn token_bridge() {
+        // https://github.com/wormhole-foundation/wormhole/blob/b09a644dac97fa8e037a16765728217ff3a1d057/clients/js/parse_tests/token-bridge-transfer-2.expected
+        let vaa = hex!("01000000010d0078588270e30e3b4cf74572b6ad4270cdd7932079692170fddaf369c7574722b75defcecf5d372cdd8fdba0f275c6b902434259b5d7da8402e25ca852ca5affaa0003a8888cf66158970861329efa69ff2461d847078cec22fd7f62606b17a1ae283127712fa50dc365faa1e6db339fefce57b13c74c2dce7d14b79051676c74bb685000487272398eb59763bb1e2466f9ebdea4e75c290b6c0386f07c20e1296b1976cb814547378922dbc5490b7fcf7279eafc0c08bd59ca97c4dbbcbd478967e17aa2d0006dd38ecb6233f1cd872a75cc0627ded36aa8f89095436f7dbe32e6655e27f217459fda35a3d7f1d656962160bfeee4e5fc6d2e1447559e7bc3ba760416317b86c010792d27a749b398dc5f085e7bcd2e0f18d6262a1ba1916787ec01854c0ccde0a8247f8892e6dff83fad6839fc054f32734255e9037ff9adc33499514e2300ba439010989f08688ae363783bfe3f25a5960a0791ce327bab7e7593393f91395e06fe50e3f7e13862ac86b9fd1f9720669bc4504e918f7e481c395f17a2fa131da05b9e7010a097d187970710297d188a2ebaedff0ad13efd16872566bae8a56377e28466b2c3c4e47853c60fe716109e55f8b453fb03a34bb1929c96f74ebd796a476ec7ab6000b68a19d198350b3caebd3c0159b8bbce022e0f026d013a1c83e40d6100c87e8bb0d692baca89cb77f4b6832dd7aaf3f2f7c482fd50be7221c046ae668228ec013000cd6f464a174d7e34797e2869785feb5f05ab614be989d238c9bd55259dbdbab2568c14f316d1820ac766e513bf5225185f16d30f0f01a092af5fb6b072ad577f0010d663f2f3ad62baa8ad541b9c38bb9df805d2cfa7072894526505b654293bacdee5e9e8c4ded7be92a3338b964482b3ce6d5275817d6a4b6a0663e1e84dcd1de3500105f773ea1d7e74770e78c4779abe4594b6a46f9131304948265bc185dcb1cdba8114915e3b1d864f48e4c694c9578524e22752e2d898af4b8e67383d72a11856700118bdbd5b5a820ecd215faf134b698402da04cc698e64464dd8df6692342e8c44314e1ae53bfde71fb2b00cd5691dae4f9b310c6150bdb551645a72863f4ff965c011286c673c4f2213969d273b939318f93a5b50c665efa8c9e245a3b8823522dafec209b1be127e74a6d5c924831e339f8bffb769f7b0f5772ed16231700bf7eece200624092e10000f4150001ec7372995d5cc8732397fb0ad35c0121e0eaa90d26f828a534cab54391b3a4f5000000000001aec5200100000000000000000000000000000000000000000000000000000000f4610900069b8857feab8184fb687f634618c035dac439dc1aeb3b5598a0f000000000010001000000000000000000000000efd4aa8f954ebdea82b8757c029fc8475a45e9cd00020000000000000000000000000000000000000000000000000000000000000000");
+        let vaa = Vaa::read(&mut vaa.as_slice()).unwrap();
+        assert!(matches!(
+            vaa.known_payload(),
+            KnownPayload::TokenBridge(TokenBridgeMessage::Transfer(_))
+        ));
+
+        // The same payload from an EVM token bridge.
+        let payload = vaa.body.payload_bytes().unwrap().to_vec();
+        let ethereum = hex!("0000000000000000000000003ee18b2214aff97000d974cf647e7c347e8fa585");
+        assert!(matches!(
+            body(KnownChainId::Ethereum, ethereum, payload.clone()).known_payload(),
+            KnownPayload::TokenBridge(TokenBridgeMessage::Transfer(_))
+        ));
+
+        // The token bridge address on the wrong chain is not a token bridge.
+        assert_eq!(
+            body(KnownChainId::Sui, ethereum, payload.clone()).known_payload(),
+            KnownPayload::Unknown(payload)
+        );
+    }
+
+    #[test]
+    fn governance() {
+        let decree = GovernanceMessage {
+            header: GovernanceHeader {
+                module: gov::core_bridge::GOVERNANCE_MODULE,
+            },
+            decree: gov::core_bridge::Decree::SetMessageFee(gov::SetMessageFee {
+                chain: ChainId::Known(KnownChainId::Solana),
+                fee: U256::from(69u64),
+            }),
+        };
+        let body = body(
+            KnownChainId::Solana,
+            gov::GOVERNANCE_EMITTER,
+            decree.to_payload_vec(),
+        );
+        assert_eq!(body.known_payload(), KnownPayload::CoreGovernance(decree));
+
+        // Governance modules are only trusted from the governance emitter.
+        let mut other = body.clone();
+        other.emitter_address[31] = 5;
+        assert!(matches!(other.known_payload(), KnownPayload::Unknown(_)));
+
+        // Unsupported modules are unknown.
+        let mut payload = body.payload_bytes().unwrap().to_vec();
+        payload[..32].copy_from_slice(&[0; 32]);
+        let mut other = body;
+        other.payload = PayloadKind::Binary(payload);
+        assert!(matches!(other.known_payload(), KnownPayload::Unknown(_)));
+    }
+
+    #[test]
+    fn circle_integration() {
+        let deposit = CctpDeposit {
+            token_address: hex!("000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+            amount: U256::from(1_000_000u64).to_be_bytes(),
+            source_cctp_domain: 0,
+            destination_cctp_domain: 6,
+            cctp_nonce: 42,
+            burn_source: [1; 32],
+            mint_recipient: [2; 32],
+            payload: b"hello".to_vec().into(),
+        };
+        // The Circle Integration contract on Ethereum.
+        let ethereum = hex!("000000000000000000000000aada05bd399372f0b0463744c09113c137636f6a");
+        assert_eq!(
+            body(KnownChainId::Ethereum, ethereum, deposit.to_payload_vec()).known_payload(),
+            KnownPayload::Cctp(deposit.clone())
+        );
+
+        // Not decoded as a deposit from another emitter.
+        assert!(matches!(
+            body(KnownChainId::Ethereum, [0; 32], deposit.to_payload_vec()).known_payload(),
+            KnownPayload::Unknown(_)
+        ));
+    }
+
+    #[test]
+    fn token_router() {
+        let order = FastMarketOrder {
+            amount_in: 1_000_000,
+            min_amount_out: 0,
+            target_chain: ChainId::Known(KnownChainId::Solana),
+            redeemer: [1; 32],
+            sender: [2; 32],
+            refund_address: [3; 32],
+            max_fee: 100,
+            init_auction_fee: 10,
+            deadline: 0,
+            redeemer_message: Vec::new().into(),
+        };
+        let sepolia = hex!("000000000000000000000000e57d917bf955fede2888aabd056202a6497f1882");
+        assert_eq!(
+            body(KnownChainId::Sepolia, sepolia, order.to_payload_vec()).known_payload(),
+            KnownPayload::LiquidityLayer(LiquidityLayerMessage::FastMarketOrder(order.clone()))
+        );
+
+        // Not decoded as a fast market order from an unknown emitter.
+        assert!(matches!(
+            body(KnownChainId::Sepolia, [0; 32], order.to_payload_vec()).known_payload(),
+            KnownPayload::Unknown(_)
+        ));
+    }
+
+    #[test]
+    fn unknown_emitter() {
+        // A token bridge attestation from an emitter which is not a known token bridge.
+        let vaa = hex!("01000000000100ff7edcd3facb7dd6e06e0bd3e178cfddd775208f3e09f0b68bba981b812258716e6e5cd42c0ba413586df1e4066e29a1a41f9a49ae05a58f5fa93590d165abf100000000007ce2ea3f000195f83a27e90c622a98c037353f271fd8f5f57b4dc18ebf5ff75a934724bd0491a43a1c0020f88a3e2002000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc200021257455448000000000000000000000000000000000000000000000000000000005772617070656420657468657200000000000000000000000000000000000000");
+        let vaa = Vaa::read(&mut vaa.as_slice()).unwrap();
+        assert_eq!(
+            vaa.known_payload(),
+            KnownPayload::Unknown(vaa.body.payload_bytes().unwrap().to_vec())
+        );
+
+        let message = Message {
+            version: 1,
+            message_ty: 0,
+            index: 7,
+            target_chain: ChainId::Known(KnownChainId::Ethereum),
+            target: vec![4; 20].into(),
+            sender: vec![5; 32].into(),
+            body: b"hello".to_vec().into(),
+        };
+        assert_eq!(
+            body(KnownChainId::Solana, [9; 32], message.to_payload_vec()).known_payload(),
+            KnownPayload::Message(message)
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Fast transfer auction math, matching the liquidity layer's matching engine.
+
+use crate::payloads::liquidity::{FastMarketOrder, SlowOrderResponse};
+
+/// Basis points are expressed in millionths.
+pub const FEE_PRECISION_MAX: u32 = 1_000_000;
+
+/// `amount * bps / FEE_PRECISION_MAX`, with `bps` capped at [`FEE_PRECISION_MAX`].
+pub fn mul_bps(amount: u64, bps: u32) -> u64 {
+    let bps = bps.min(FEE_PRECISION_MAX);
+    (u128::from(amount) * u128::from(bps) / u128::from(FEE_PRECISION_MAX)) as u64
+}
+
+/// Auction configuration of a matching engine. Durations are in slots (blocks) of the chain
+/// running the auction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AuctionParameters {
+    /// Share of the penalty which goes to the user rather than the executor.
+    pub user_penalty_reward_bps: u32,
+    /// Share of the security deposit forfeited as soon as the grace period ends.
+    pub initial_penalty_bps: u32,
+    /// Slots during which offers may be improved.
+    pub duration: u16,
+    /// Slots after the auction during which the winner may execute without penalty.
+    pub grace_period: u16,
+    /// Slots over which the penalty grows from the initial penalty to the whole deposit.
+    pub penalty_period: u16,
+    /// Minimum improvement of a new offer over the best offer.
+    pub min_offer_delta_bps: u32,
+    pub security_deposit_base: u64,
+    pub security_deposit_bps: u32,
+}
+
+/// The part of the winner's security deposit forfeited for executing late.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DepositPenalty {
+    /// Paid to whoever executes the order.
+    pub penalty: u64,
+    /// Added to the amount delivered to the user.
+    pub user_reward: u64,
+}
+
+impl AuctionParameters {
+    /// Security deposit an offer must lock for `order`, on top of the offer itself.
+    pub fn security_deposit(&self, order: &FastMarketOrder) -> u64 {
+        order
+            .max_fee
+            .saturating_add(self.security_deposit_base)
+            .saturating_add(mul_bps(order.amount_in, self.security_deposit_bps))
+    }
+
+    /// Smallest amount by which a new offer must undercut `best_offer`.
+    pub fn min_offer_delta(&self, best_offer: u64) -> u64 {
+        mul_bps(best_offer, self.min_offer_delta_bps)
+    }
+
+    /// Whether `offer` may replace `best_offer`.
+    pub fn is_improved_offer(&self, best_offer: u64, offer: u64) -> bool {
+        offer <= best_offer.saturating_sub(self.min_offer_delta(best_offer))
+    }
+
+    /// Slot at which the grace period ends and penalties start accruing, relative to the
+    /// auction's start.
+    pub fn penalty_start(&self) -> u64 {
+        u64::from(self.duration) + u64::from(self.grace_period)
+    }
+
+    /// Penalty for executing `slots_elapsed` slots after the auction started.
+    pub fn deposit_penalty(&self, security_deposit: u64, slots_elapsed: u64) -> DepositPenalty {
+        let late = slots_elapsed.saturating_sub(self.penalty_start());
+        if late == 0 {
+            return DepositPenalty::default();
+        }
+
+        let amount = if late >= u64::from(self.penalty_period) {
+            security_deposit
+        } else {
+            let base = mul_bps(security_deposit, self.initial_penalty_bps);
+            let growth = u128::from(security_deposit - base) * u128::from(late)
+                / u128::from(self.penalty_period);
+            base + growth as u64
+        };
+
+        let user_reward = mul_bps(amount, self.user_penalty_reward_bps);
+        DepositPenalty {
+            penalty: amount - user_reward,
+            user_reward,
+        }
+    }
+
+    /// Penalties for every slot of the penalty period, starting with the first late slot.
+    pub fn penalty_schedule(&self, security_deposit: u64) -> Vec<DepositPenalty> {
+        let start = self.penalty_start();
+        (1..=u64::from(self.penalty_period))
+            .map(|late| self.deposit_penalty(security_deposit, start + late))
+            .collect()
+    }
+}
+
+impl FastMarketOrder {
+    /// Whether offers may still be placed for the order at unix time `now`. A zero deadline
+    /// means the order never expires.
+    pub fn is_executable(&self, now: u32) -> bool {
+        self.deadline == 0 || now < self.deadline
+    }
+
+    /// Amount delivered to the user in the `FastFill` if the auction settles at `offer_price`
+    /// and the executor forfeits `penalty`.
+    ///
+    /// Returns `None` if the offer exceeds the order's maximum fee, if the fees exceed the
+    /// order's amount or if the user would receive less than the order's minimum amount.
+    pub fn fast_fill_amount(&self, offer_price: u64, penalty: DepositPenalty) -> Option<u64> {
+        if offer_price > self.max_fee {
+            return None;
+        }
+        let amount = self
+            .amount_in
+            .checked_sub(offer_price)?
+            .checked_sub(self.init_auction_fee)?
+            .checked_add(penalty.user_reward)?;
+        (amount >= self.min_amount_out).then_some(amount)
+    }
+}
+
+impl SlowOrderResponse {
+    /// Amount left for the user when a fast order without an auction settles through the
+    /// slow path.
+    pub fn settled_amount(&self, amount: u64) -> Option<u64> {
+        amount.checked_sub(self.base_fee)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use wormhole_io::deploys::{ChainId, KnownChainId};
+
+    use super::*;
+
+    const PARAMS: AuctionParameters = AuctionParameters {
+        user_penalty_reward_bps: 250_000,
+        initial_penalty_bps: 250_000,
+        duration: 2,
+        grace_period: 5,
+        penalty_period: 10,
+        min_offer_delta_bps: 20_000,
+        security_deposit_base: 4_200_000,
+        security_deposit_bps: 5_000,
+    };
+
+    fn order() -> FastMarketOrder {
+        FastMarketOrder {
+            amount_in: 1_000_000_000,
+            min_amount_out: 990_000_000,
+            target_chain: ChainId::Known(KnownChainId::Base),
+            redeemer: [1; 32],
+            sender: [2; 32],
+            refund_address: [3; 32],
+            max_fee: 5_000_000,
+            init_auction_fee: 100_000,
+            deadline: 1_700_000_000,
+            redeemer_message: Vec::new().into(),
+        }
+    }
+
+    #[test]
+    fn penalties() {
+        let deposit = PARAMS.security_deposit(&order());
+        assert_eq!(deposit, 5_000_000 + 4_200_000 + 5_000_000);
+
+        // No penalty during the auction and grace period.
+        for slot in 0..=PARAMS.penalty_start() {
+            assert_eq!(
+                PARAMS.deposit_penalty(deposit, slot),
+                DepositPenalty::default()
+            );
+        }
+
+        let schedule = PARAMS.penalty_schedule(deposit);
+        assert_eq!(schedule.len(), 10);
+
+        // A quarter of the deposit plus a tenth of the rest, a quarter of which goes to the
+        // user.
+        let first = deposit / 4 + (deposit - deposit / 4) / 10;
+        assert_eq!(
+            schedule[0],
+            DepositPenalty {
+                penalty: first - first / 4,
+                user_reward: first / 4,
+            }
+        );
+        assert!(schedule.windows(2).all(|w| w[0].penalty <= w[1].penalty));
+
+        // The whole deposit is forfeited once the penalty period ends.
+        let last = schedule[9];
+        assert_eq!(last.penalty + last.user_reward, deposit);
+        assert_eq!(PARAMS.deposit_penalty(deposit, u64::MAX), last);
+    }
+
+    #[test]
+    fn offers() {
+        assert_eq!(PARAMS.min_offer_delta(1_000_000), 20_000);
+        assert!(PARAMS.is_improved_offer(1_000_000, 980_000));
+        assert!(!PARAMS.is_improved_offer(1_000_000, 980_001));
+    }
+
+    #[test]
+    fn fast_fill_amount() {
+        let order = order();
+        assert!(order.is_executable(1_699_999_999));
+        assert!(!order.is_executable(1_700_000_000));
+        assert!(FastMarketOrder {
+            deadline: 0,
+            ..order.clone()
+        }
+        .is_executable(u32::MAX));
+
+        assert_eq!(
+            order.fast_fill_amount(1_000_000, DepositPenalty::default()),
+            Some(998_900_000)
+        );
+        assert_eq!(
+            order.fast_fill_amount(
+                1_000_000,
+                DepositPenalty {
+                    penalty: 3,
+                    user_reward: 1
+                }
+            ),
+            Some(998_900_001)
+        );
+        // Above the maximum fee.
+        assert_eq!(
+            order.fast_fill_amount(5_000_001, DepositPenalty::default()),
+            None
+        );
+        // Below the minimum amount out.
+        assert_eq!(
+            FastMarketOrder {
+                min_amount_out: 999_000_000,
+                ..order
+            }
+            .fast_fill_amount(1_000_000, DepositPenalty::default()),
+            None
+        );
+
+        assert_eq!(
+            SlowOrderResponse { base_fee: 10 }.settled_amount(100),
+            Some(90)
+        );
+    }
+}
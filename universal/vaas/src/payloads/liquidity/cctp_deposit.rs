@@ -1,19 +1,47 @@
 //! A CCTP deposit transfer with message
 
+use wormhole_io::deploys::{deploys, CctpDeployment, NetEnv};
+
 use crate::{io::WriteableSequence, Readable, TypePrefixedPayload, Writeable};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct CctpDeposit {
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub token_address: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub amount: [u8; 32],
     pub source_cctp_domain: u32,
     pub destination_cctp_domain: u32,
     pub cctp_nonce: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub burn_source: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub mint_recipient: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_bytes"))]
     pub payload: WriteableSequence<u16, Vec<u8>>,
 }
 
+impl CctpDeposit {
+    /// The CCTP deployment of the source domain in `net_env`, if known.
+    pub fn source_cctp(&self, net_env: NetEnv) -> Option<&'static CctpDeployment> {
+        deploys::find_cctp_by_domain(self.source_cctp_domain, net_env)
+    }
+
+    /// The CCTP deployment of the destination domain in `net_env`, if known.
+    pub fn destination_cctp(&self, net_env: NetEnv) -> Option<&'static CctpDeployment> {
+        deploys::find_cctp_by_domain(self.destination_cctp_domain, net_env)
+    }
+
+    /// Whether the burned token is USDC on the source domain in `net_env`. Unknown source domains
+    /// are never USDC.
+    pub fn is_source_usdc(&self, net_env: NetEnv) -> bool {
+        self.source_cctp(net_env)
+            .is_some_and(|cctp| cctp.usdc_token_address() == self.token_address)
+    }
+}
+
 impl Readable for CctpDeposit {
     const SIZE: Option<usize> = None;
 
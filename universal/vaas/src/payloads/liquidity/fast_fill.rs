@@ -5,11 +5,20 @@ use wormhole_io::{deploys::ChainId, WriteableSequence};
 use crate::{Readable, TypePrefixedPayload, Writeable};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct FastFill {
     pub fill_amount: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub source_chain: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub order_sender: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub redeemer: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_bytes"))]
     pub redeemer_message: WriteableSequence<u16, Vec<u8>>,
 }
 
@@ -6,16 +6,26 @@ use crate::{
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct FastMarketOrder {
     pub amount_in: u64,
     pub min_amount_out: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub target_chain: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub redeemer: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub sender: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub refund_address: [u8; 32],
     pub max_fee: u64,
     pub init_auction_fee: u64,
     pub deadline: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_bytes"))]
     pub redeemer_message: WriteableSequence<u16, Vec<u8>>,
 }
 
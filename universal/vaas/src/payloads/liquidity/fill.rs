@@ -0,0 +1,63 @@
+//! Fill
+
+use wormhole_io::{deploys::ChainId, WriteableSequence};
+
+use crate::{Readable, TypePrefixedPayload, Writeable};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Fill {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
+    pub source_chain: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
+    pub order_sender: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
+    pub redeemer: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_bytes"))]
+    pub redeemer_message: WriteableSequence<u16, Vec<u8>>,
+}
+
+impl Readable for Fill {
+    const SIZE: Option<usize> = None;
+
+    fn read<R>(reader: &mut R) -> std::io::Result<Self>
+    where
+        R: std::io::Read,
+    {
+        Ok(Self {
+            source_chain: Readable::read(reader)?,
+            order_sender: Readable::read(reader)?,
+            redeemer: Readable::read(reader)?,
+            redeemer_message: Readable::read(reader)?,
+        })
+    }
+}
+
+impl Writeable for Fill {
+    fn written_size(&self) -> usize {
+        self.source_chain.written_size()
+            + self.order_sender.written_size()
+            + self.redeemer.written_size()
+            + self.redeemer_message.written_size()
+    }
+
+    fn write<W>(&self, writer: &mut W) -> std::io::Result<()>
+    where
+        Self: Sized,
+        W: std::io::Write,
+    {
+        self.source_chain.write(writer)?;
+        self.order_sender.write(writer)?;
+        self.redeemer.write(writer)?;
+        self.redeemer_message.write(writer)?;
+        Ok(())
+    }
+}
+
+impl TypePrefixedPayload for Fill {
+    const TYPE: &[u8] = &[1];
+}
@@ -1,9 +1,311 @@
+pub mod auction;
+
 mod cctp_deposit;
 mod fast_fill;
 mod fast_market_order;
+mod fill;
 mod slow_order_response;
 
 pub use cctp_deposit::CctpDeposit;
 pub use fast_fill::FastFill;
 pub use fast_market_order::FastMarketOrder;
+pub use fill::Fill;
 pub use slow_order_response::SlowOrderResponse;
+
+use crate::{Readable, TypePrefixedPayload, Writeable};
+
+/// Messages emitted by the liquidity layer's token routers and matching engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum LiquidityLayerMessage {
+    Deposit(CctpDeposit),
+    FastMarketOrder(FastMarketOrder),
+    FastFill(FastFill),
+}
+
+impl Readable for LiquidityLayerMessage {
+    const SIZE: Option<usize> = None;
+
+    fn read<R>(reader: &mut R) -> std::io::Result<Self>
+    where
+        Self: Sized,
+        R: std::io::Read,
+    {
+        match [u8::read(reader)?].as_slice() {
+            <CctpDeposit as TypePrefixedPayload>::TYPE => {
+                Ok(LiquidityLayerMessage::Deposit(Readable::read(reader)?))
+            }
+            <FastMarketOrder as TypePrefixedPayload>::TYPE => Ok(
+                LiquidityLayerMessage::FastMarketOrder(Readable::read(reader)?),
+            ),
+            <FastFill as TypePrefixedPayload>::TYPE => {
+                Ok(LiquidityLayerMessage::FastFill(Readable::read(reader)?))
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Invalid liquidity layer message type",
+            )),
+        }
+    }
+}
+
+impl Writeable for LiquidityLayerMessage {
+    fn write<W>(&self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: std::io::Write,
+    {
+        match self {
+            LiquidityLayerMessage::Deposit(inner) => inner.write_payload(writer),
+            LiquidityLayerMessage::FastMarketOrder(inner) => inner.write_payload(writer),
+            LiquidityLayerMessage::FastFill(inner) => inner.write_payload(writer),
+        }
+    }
+
+    fn written_size(&self) -> usize {
+        1 + match self {
+            LiquidityLayerMessage::Deposit(inner) => inner.written_size(),
+            LiquidityLayerMessage::FastMarketOrder(inner) => inner.written_size(),
+            LiquidityLayerMessage::FastFill(inner) => inner.written_size(),
+        }
+    }
+}
+
+impl TypePrefixedPayload for LiquidityLayerMessage {
+    const TYPE: &[u8] = &[];
+}
+
+/// Messages carried in the payload of a liquidity layer [`CctpDeposit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum DepositMessage {
+    Fill(Fill),
+    SlowOrderResponse(SlowOrderResponse),
+}
+
+impl Readable for DepositMessage {
+    const SIZE: Option<usize> = None;
+
+    fn read<R>(reader: &mut R) -> std::io::Result<Self>
+    where
+        Self: Sized,
+        R: std::io::Read,
+    {
+        match [u8::read(reader)?].as_slice() {
+            <Fill as TypePrefixedPayload>::TYPE => {
+                Ok(DepositMessage::Fill(Readable::read(reader)?))
+            }
+            <SlowOrderResponse as TypePrefixedPayload>::TYPE => {
+                Ok(DepositMessage::SlowOrderResponse(Readable::read(reader)?))
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Invalid deposit message type",
+            )),
+        }
+    }
+}
+
+impl Writeable for DepositMessage {
+    fn write<W>(&self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: std::io::Write,
+    {
+        match self {
+            DepositMessage::Fill(inner) => inner.write_payload(writer),
+            DepositMessage::SlowOrderResponse(inner) => inner.write_payload(writer),
+        }
+    }
+
+    fn written_size(&self) -> usize {
+        1 + match self {
+            DepositMessage::Fill(inner) => inner.written_size(),
+            DepositMessage::SlowOrderResponse(inner) => inner.written_size(),
+        }
+    }
+}
+
+impl TypePrefixedPayload for DepositMessage {
+    const TYPE: &[u8] = &[];
+}
+
+impl CctpDeposit {
+    /// Decode the deposit's payload as a liquidity layer [`DepositMessage`].
+    pub fn deposit_message(&self) -> Option<DepositMessage> {
+        DepositMessage::read_slice(&self.payload).ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use hex_literal::hex;
+    use wormhole_io::deploys::{ChainId, KnownChainId, NetEnv};
+    use wormhole_raw_vaas::liquidity as raw;
+
+    use super::*;
+
+    fn fill() -> Fill {
+        Fill {
+            source_chain: ChainId::Known(KnownChainId::Arbitrum),
+            order_sender: [1; 32],
+            redeemer: [2; 32],
+            redeemer_message: b"All your base are belong to us.".to_vec().into(),
+        }
+    }
+
+    fn deposit(message: &DepositMessage) -> CctpDeposit {
+        CctpDeposit {
+            token_address: [3; 32],
+            amount: hex!("00000000000000000000000000000000000000000000000000000000000f4240"),
+            source_cctp_domain: 3,
+            destination_cctp_domain: 6,
+            cctp_nonce: 420,
+            burn_source: [4; 32],
+            mint_recipient: [5; 32],
+            payload: message.to_payload_vec().into(),
+        }
+    }
+
+    fn fast_market_order() -> FastMarketOrder {
+        FastMarketOrder {
+            amount_in: 1234567890,
+            min_amount_out: 69420,
+            target_chain: ChainId::Known(KnownChainId::Base),
+            redeemer: [6; 32],
+            sender: [7; 32],
+            refund_address: [8; 32],
+            max_fee: 1_000_000,
+            init_auction_fee: 1_000,
+            deadline: 420,
+            redeemer_message: vec![9; 4].into(),
+        }
+    }
+
+    fn fast_fill() -> FastFill {
+        FastFill {
+            fill_amount: 420,
+            source_chain: ChainId::Known(KnownChainId::Ethereum),
+            order_sender: [10; 32],
+            redeemer: [11; 32],
+            redeemer_message: Vec::new().into(),
+        }
+    }
+
+    #[test]
+    fn deposit_messages() {
+        for message in [
+            DepositMessage::Fill(fill()),
+            DepositMessage::SlowOrderResponse(SlowOrderResponse { base_fee: 69 }),
+        ] {
+            let encoded = message.to_payload_vec();
+            assert_eq!(message.payload_written_size(), encoded.len());
+            assert_eq!(DepositMessage::read_slice(&encoded).unwrap(), message);
+
+            let raw = raw::LiquidityLayerDepositMessage::parse(&encoded).unwrap();
+            match (&message, raw) {
+                (DepositMessage::Fill(owned), raw::LiquidityLayerDepositMessage::Fill(raw)) => {
+                    assert_eq!(owned.source_chain, raw.source_chain());
+                    assert_eq!(owned.order_sender, raw.order_sender());
+                    assert_eq!(owned.redeemer, raw.redeemer());
+                    assert_eq!(
+                        owned.redeemer_message.as_slice(),
+                        raw.redeemer_message().as_ref()
+                    );
+                }
+                (
+                    DepositMessage::SlowOrderResponse(owned),
+                    raw::LiquidityLayerDepositMessage::SlowOrderResponse(raw),
+                ) => assert_eq!(owned.base_fee, raw.base_fee()),
+                _ => panic!("mismatched deposit message"),
+            }
+
+            assert_eq!(deposit(&message).deposit_message(), Some(message));
+        }
+    }
+
+    #[test]
+    fn liquidity_layer_messages() {
+        let messages = [
+            LiquidityLayerMessage::Deposit(deposit(&DepositMessage::Fill(fill()))),
+            LiquidityLayerMessage::FastMarketOrder(fast_market_order()),
+            LiquidityLayerMessage::FastFill(fast_fill()),
+        ];
+        for message in messages {
+            let encoded = message.to_payload_vec();
+            assert_eq!(message.payload_written_size(), encoded.len());
+            assert_eq!(
+                LiquidityLayerMessage::read_slice(&encoded).unwrap(),
+                message
+            );
+
+            let raw = raw::LiquidityLayerMessage::parse(&encoded).unwrap();
+            assert_eq!(raw.span(), &encoded[1..]);
+            match (&message, raw) {
+                (
+                    LiquidityLayerMessage::Deposit(owned),
+                    raw::LiquidityLayerMessage::Deposit(raw),
+                ) => {
+                    assert_eq!(owned.token_address, raw.token_address());
+                    assert_eq!(owned.amount, raw.amount());
+                    assert_eq!(owned.source_cctp_domain, raw.source_cctp_domain());
+                    assert_eq!(owned.destination_cctp_domain, raw.destination_cctp_domain());
+                    assert_eq!(owned.cctp_nonce, raw.cctp_nonce());
+                    assert_eq!(owned.burn_source, raw.burn_source());
+                    assert_eq!(owned.mint_recipient, raw.mint_recipient());
+                    assert_eq!(owned.payload.as_slice(), raw.payload().as_ref());
+                }
+                (
+                    LiquidityLayerMessage::FastMarketOrder(owned),
+                    raw::LiquidityLayerMessage::FastMarketOrder(raw),
+                ) => {
+                    assert_eq!(owned.amount_in, raw.amount_in());
+                    assert_eq!(owned.min_amount_out, raw.min_amount_out());
+                    assert_eq!(owned.target_chain, raw.target_chain());
+                    assert_eq!(owned.redeemer, raw.redeemer());
+                    assert_eq!(owned.sender, raw.sender());
+                    assert_eq!(owned.refund_address, raw.refund_address());
+                    assert_eq!(owned.max_fee, raw.max_fee());
+                    assert_eq!(owned.init_auction_fee, raw.init_auction_fee());
+                    assert_eq!(owned.deadline, raw.deadline());
+                    assert_eq!(
+                        owned.redeemer_message.as_slice(),
+                        raw.redeemer_message().as_ref()
+                    );
+                }
+                (
+                    LiquidityLayerMessage::FastFill(owned),
+                    raw::LiquidityLayerMessage::FastFill(raw),
+                ) => {
+                    assert_eq!(owned.fill_amount, raw.fill_amount());
+                    assert_eq!(owned.source_chain, raw.source_chain());
+                    assert_eq!(owned.order_sender, raw.order_sender());
+                    assert_eq!(owned.redeemer, raw.redeemer());
+                    assert_eq!(
+                        owned.redeemer_message.as_slice(),
+                        raw.redeemer_message().as_ref()
+                    );
+                }
+                _ => panic!("mismatched liquidity layer message"),
+            }
+        }
+    }
+
+    #[test]
+    fn deposit_cctp_domains() {
+        let mut deposit = deposit(&DepositMessage::Fill(fill()));
+        assert!(!deposit.is_source_usdc(NetEnv::MainNet));
+
+        let source = deposit.source_cctp(NetEnv::MainNet).unwrap();
+        assert_eq!(source.chain_id, KnownChainId::Arbitrum);
+        deposit.token_address = source.usdc_token_address();
+        assert!(deposit.is_source_usdc(NetEnv::MainNet));
+        assert!(!deposit.is_source_usdc(NetEnv::TestNet));
+
+        let destination = deposit.destination_cctp(NetEnv::TestNet).unwrap();
+        assert_eq!(destination.chain_id, KnownChainId::BaseSepolia);
+
+        deposit.destination_cctp_domain = u32::MAX;
+        assert!(deposit.destination_cctp(NetEnv::MainNet).is_none());
+    }
+}
@@ -1,6 +1,12 @@
-use std::io;
+use std::{fmt, io};
 
-use wormhole_io::{deploys::ChainId, WriteableSequence};
+use wormhole_io::{
+    deploys::{
+        deploys::{DEVNETS_BY_ID, MAINNETS_BY_ID, TESTNETS_BY_ID},
+        ChainId, CoreDeployment, NetEnv,
+    },
+    WriteableSequence,
+};
 
 use crate::{Readable, TypePrefixedPayload, Writeable};
 
@@ -8,17 +14,199 @@ impl TypePrefixedPayload for Message {
     const TYPE: &[u8] = &[0xbb];
 }
 
+/// Largest target, sender or body of a [`Message`], as each is prefixed with a `u16` length.
+pub const MAX_MESSAGE_FIELD_LEN: usize = u16::MAX as usize;
+
+/// A generic cross-chain message from an application to a contract on another chain.
+///
+/// The target and sender are addresses in the native format of their chains, so they are
+/// not necessarily 32 bytes long. Use [`Message::builder`] to construct messages with
+/// checked field lengths.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Message {
+    /// Version of the application's body encoding, for the target to dispatch on.
     pub version: u8,
+    /// Application-defined kind of the body.
     pub message_ty: u8,
+    /// Application-defined index of the message, e.g. to order or deduplicate the messages
+    /// of one sender.
     pub index: u64,
+    /// Chain of the target.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub target_chain: ChainId,
+    /// Address of the receiving contract on the target chain.
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_bytes"))]
     pub target: WriteableSequence<u16, Vec<u8>>,
+    /// Address of the sending contract on the emitter chain.
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_bytes"))]
     pub sender: WriteableSequence<u16, Vec<u8>>,
+    /// Application payload.
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_bytes"))]
     pub body: WriteableSequence<u16, Vec<u8>>,
 }
 
+impl Message {
+    /// Start building a message to `target` on `target_chain`.
+    pub fn builder(target_chain: ChainId, target: impl Into<Vec<u8>>) -> MessageBuilder {
+        MessageBuilder::new(target_chain, target)
+    }
+
+    /// Start building a reply to this message. The reply targets this message's sender on
+    /// `source_chain`, the emitter chain of this message, and is sent by this message's
+    /// target. The version and kind are kept, and the index is unchanged.
+    pub fn reply(&self, source_chain: ChainId) -> MessageBuilder {
+        MessageBuilder::new(source_chain, self.sender.as_slice())
+            .sender(self.target.as_slice())
+            .version(self.version)
+            .message_ty(self.message_ty)
+            .index(self.index)
+    }
+
+    /// Whether the message is addressed to `address` on `chain`.
+    pub fn is_for(&self, chain: ChainId, address: &[u8]) -> bool {
+        self.target_chain == chain && *self.target == address
+    }
+
+    /// The deployment of the target chain in `net_env`, if the chain is known there.
+    pub fn target_deployment(&self, net_env: NetEnv) -> Option<&'static CoreDeployment> {
+        let chain = self.target_chain.to_known()?;
+        let deployments = match net_env {
+            NetEnv::MainNet => &MAINNETS_BY_ID,
+            NetEnv::TestNet => &TESTNETS_BY_ID,
+            NetEnv::DevNet => &DEVNETS_BY_ID,
+        };
+        deployments.get(&chain).copied()
+    }
+
+    /// The target left-padded to 32 bytes, as it would appear as a Wormhole universal address.
+    ///
+    /// Returns `None` if the target is longer than 32 bytes.
+    pub fn target_universal_address(&self) -> Option<[u8; 32]> {
+        let len = self.target.len();
+        let mut out = [0; 32];
+        out.get_mut(32usize.checked_sub(len)?..)?
+            .copy_from_slice(&self.target);
+        Some(out)
+    }
+}
+
+/// Why a [`Message`] could not be built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MessageError {
+    /// A field is longer than [`MAX_MESSAGE_FIELD_LEN`].
+    FieldTooLong { field: &'static str, len: usize },
+}
+
+impl fmt::Display for MessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FieldTooLong { field, len } => write!(
+                f,
+                "message {field} is {len} bytes, at most {MAX_MESSAGE_FIELD_LEN} allowed"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MessageError {}
+
+/// Builds a [`Message`], checking the length of its variable fields.
+///
+/// Unless overridden, the version, kind and index are zero and the sender and body are empty.
+///
+/// ```
+/// use wormhole_vaas::payloads::Message;
+/// use wormhole_vaas::io::deploys::{ChainId, KnownChainId};
+///
+/// let message = Message::builder(ChainId::Known(KnownChainId::Ethereum), [4; 20])
+///     .sender([5; 32])
+///     .body(b"hello".to_vec())
+///     .index(7)
+///     .build()
+///     .unwrap();
+/// assert_eq!(*message.body, b"hello");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MessageBuilder {
+    version: u8,
+    message_ty: u8,
+    index: u64,
+    target_chain: ChainId,
+    target: Vec<u8>,
+    sender: Vec<u8>,
+    body: Vec<u8>,
+}
+
+impl MessageBuilder {
+    pub fn new(target_chain: ChainId, target: impl Into<Vec<u8>>) -> Self {
+        Self {
+            version: 0,
+            message_ty: 0,
+            index: 0,
+            target_chain,
+            target: target.into(),
+            sender: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    pub fn version(mut self, version: u8) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn message_ty(mut self, message_ty: u8) -> Self {
+        self.message_ty = message_ty;
+        self
+    }
+
+    pub fn index(mut self, index: u64) -> Self {
+        self.index = index;
+        self
+    }
+
+    pub fn sender(mut self, sender: impl Into<Vec<u8>>) -> Self {
+        self.sender = sender.into();
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    pub fn build(self) -> Result<Message, MessageError> {
+        for (field, value) in [
+            ("target", &self.target),
+            ("sender", &self.sender),
+            ("body", &self.body),
+        ] {
+            if value.len() > MAX_MESSAGE_FIELD_LEN {
+                return Err(MessageError::FieldTooLong {
+                    field,
+                    len: value.len(),
+                });
+            }
+        }
+
+        Ok(Message {
+            version: self.version,
+            message_ty: self.message_ty,
+            index: self.index,
+            target_chain: self.target_chain,
+            target: self.target.into(),
+            sender: self.sender.into(),
+            body: self.body.into(),
+        })
+    }
+}
+
 impl Readable for Message {
     const SIZE: Option<usize> = None;
 
@@ -64,23 +252,32 @@ impl Writeable for Message {
     }
 
     fn written_size(&self) -> usize {
-        1 + 8 + 2 + 2 + self.sender.len() + 2 + self.target.len() + 2 + self.body.len()
+        self.version.written_size()
+            + self.message_ty.written_size()
+            + self.index.written_size()
+            + self.target_chain.written_size()
+            + self.target.written_size()
+            + self.sender.written_size()
+            + self.body.written_size()
     }
 }
 
 #[cfg(test)]
 mod test {
     use hex_literal::hex;
+    use wormhole_io::deploys::KnownChainId;
 
     use super::*;
     #[test]
     fn it_roundtrips() {
-        let message = hex!(
+        let encoded = hex!(
             "0000000000000000000012340002567800147fa9385be102ac3eac297483dd6233d62b3e149600029abc"
         );
 
-        let message = Message::read(&mut &message[..]).unwrap();
+        let message = Message::read(&mut &encoded[..]).unwrap();
         dbg!(&message);
+        assert_eq!(message.to_vec(), encoded);
+        assert_eq!(message.written_size(), encoded.len());
 
         assert_eq!(message.index, 0);
         assert_eq!(message.target_chain, 0x1234);
@@ -91,4 +288,90 @@ mod test {
         );
         assert_eq!(*message.body, hex!("9abc"));
     }
+
+    #[test]
+    fn builder() {
+        let ethereum = ChainId::Known(KnownChainId::Ethereum);
+        let message = Message::builder(ethereum, [4; 20])
+            .version(1)
+            .message_ty(2)
+            .index(3)
+            .sender([5; 32])
+            .body(b"hello".to_vec())
+            .build()
+            .unwrap();
+        assert_eq!(
+            message.payload_written_size(),
+            message.to_payload_vec().len()
+        );
+        assert_eq!(
+            Message::read_slice(&message.to_payload_vec()).unwrap(),
+            message
+        );
+
+        assert_eq!(
+            Message::builder(ethereum, [4; 20])
+                .body(vec![0; MAX_MESSAGE_FIELD_LEN + 1])
+                .build(),
+            Err(MessageError::FieldTooLong {
+                field: "body",
+                len: MAX_MESSAGE_FIELD_LEN + 1
+            })
+        );
+        assert!(Message::builder(ethereum, vec![0; MAX_MESSAGE_FIELD_LEN])
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn routing() {
+        let ethereum = ChainId::Known(KnownChainId::Ethereum);
+        let solana = ChainId::Known(KnownChainId::Solana);
+        let message = Message::builder(ethereum, [4; 20])
+            .sender([5; 32])
+            .index(7)
+            .build()
+            .unwrap();
+
+        assert!(message.is_for(ethereum, &[4; 20]));
+        assert!(!message.is_for(solana, &[4; 20]));
+        assert_eq!(
+            message.target_deployment(NetEnv::MainNet).unwrap().name,
+            "Ethereum"
+        );
+        assert_eq!(
+            message.target_deployment(NetEnv::TestNet).unwrap().name,
+            "Goerli"
+        );
+        let unknown = Message {
+            target_chain: ChainId::from_u16(u16::MAX),
+            ..message.clone()
+        };
+        assert!(unknown.target_deployment(NetEnv::MainNet).is_none());
+
+        let mut padded = [0; 32];
+        padded[12..].copy_from_slice(&[4; 20]);
+        assert_eq!(message.target_universal_address(), Some(padded));
+
+        let reply = message.reply(solana).build().unwrap();
+        assert!(reply.is_for(solana, &[5; 32]));
+        assert_eq!(*reply.sender, [4; 20]);
+        assert_eq!(reply.index, 7);
+    }
+
+    #[test]
+    fn target_chain_names() {
+        let target_chain = "arbitrum_sepolia".parse().unwrap();
+        let message = Message::builder(target_chain, [4; 20]).build().unwrap();
+        assert_eq!(message.target_chain, KnownChainId::ArbitrumSepolia);
+
+        #[cfg(feature = "serde")]
+        {
+            let mut json = serde_json::to_value(&message).unwrap();
+            assert_eq!(json["targetChain"], "ArbitrumSepolia");
+            json["targetChain"] = "fuji".into();
+            let message: Message = serde_json::from_value(json).unwrap();
+            assert_eq!(message.target_chain, KnownChainId::Avalanche);
+        }
+    }
 }
@@ -2,13 +2,17 @@ use std::io;
 
 use crate::{Readable, TypePrefixedPayload, Writeable};
 
+mod known;
 mod message;
+mod registry;
 
 pub mod gov;
 pub mod liquidity;
 pub mod token_bridge;
 
-pub use message::Message;
+pub use known::KnownPayload;
+pub use message::{Message, MessageBuilder, MessageError, MAX_MESSAGE_FIELD_LEN};
+pub use registry::{DynPayload, PayloadDecoder, PayloadRegistry};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(
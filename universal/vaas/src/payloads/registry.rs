@@ -0,0 +1,412 @@
+use std::{any::Any, collections::HashMap, fmt, sync::OnceLock};
+
+use wormhole_io::deploys::ChainId;
+
+use crate::{
+    payloads::{
+        gov::{self, GovernanceMessage},
+        KnownPayload,
+    },
+    TypePrefixedPayload, VaaBody,
+};
+
+/// A payload decoded through a [`PayloadRegistry`].
+///
+/// Use [`DynPayload::as_any`] to downcast to the concrete payload type.
+pub trait DynPayload: fmt::Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    /// The concrete payload, for [`Box::downcast`].
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+
+    /// The payload as JSON, if it was registered with serde support.
+    #[cfg(feature = "serde")]
+    fn to_json(&self) -> Option<serde_json::Value> {
+        None
+    }
+}
+
+impl dyn DynPayload {
+    /// Downcast to the concrete payload type.
+    pub fn downcast_ref<P: Any>(&self) -> Option<&P> {
+        self.as_any().downcast_ref()
+    }
+}
+
+impl DynPayload for KnownPayload {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_json(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(self).ok()
+    }
+}
+
+struct Plain<P>(P);
+
+impl<P: fmt::Debug> fmt::Debug for Plain<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<P: fmt::Debug + Send + Sync + 'static> DynPayload for Plain<P> {
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        Box::new(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct Serializable<P>(P);
+
+#[cfg(feature = "serde")]
+impl<P: fmt::Debug> fmt::Debug for Serializable<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<P: fmt::Debug + serde::Serialize + Send + Sync + 'static> DynPayload for Serializable<P> {
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        Box::new(self.0)
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.0).ok()
+    }
+}
+
+/// Decodes a payload, returning `None` if the bytes are not a valid payload.
+pub type PayloadDecoder = Box<dyn Fn(&[u8]) -> Option<Box<dyn DynPayload>> + Send + Sync>;
+
+/// Application-defined payload decoders, keyed by emitter or by governance module.
+///
+/// Decoders registered for an emitter apply to every message from that `(ChainId, emitter)`
+/// pair. Decoders registered for a governance module apply to messages from the governance
+/// emitter whose payload starts with that module.
+///
+/// A registry is either passed to [`VaaBody::read_payload_with`], or installed once with
+/// [`PayloadRegistry::install_global`] and used through [`VaaBody::decode_registered`].
+/// [`VaaBody::read_payload`] never consults a registry.
+///
+/// ```
+/// use wormhole_vaas::payloads::{Message, PayloadRegistry};
+/// use wormhole_vaas::io::deploys::{ChainId, KnownChainId};
+///
+/// let mut registry = PayloadRegistry::new();
+/// registry.register_emitter::<Message>(ChainId::Known(KnownChainId::Solana), [1; 32]);
+/// ```
+#[derive(Default)]
+pub struct PayloadRegistry {
+    emitters: HashMap<(ChainId, [u8; 32]), PayloadDecoder>,
+    governance: HashMap<[u8; 32], PayloadDecoder>,
+}
+
+impl fmt::Debug for PayloadRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PayloadRegistry")
+            .field("emitters", &self.emitters.keys().collect::<Vec<_>>())
+            .field("governance", &self.governance.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+static GLOBAL: OnceLock<PayloadRegistry> = OnceLock::new();
+
+impl PayloadRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Install this registry for the whole process, so that [`VaaBody::decode_registered`] uses
+    /// its decoders. A registry can only be installed once; if one already is, `self` is returned.
+    pub fn install_global(self) -> Result<(), Self> {
+        GLOBAL.set(self)
+    }
+
+    /// The registry installed with [`PayloadRegistry::install_global`], if any.
+    pub fn global() -> Option<&'static Self> {
+        GLOBAL.get()
+    }
+
+    /// Decode payloads from this emitter as `P`.
+    pub fn register_emitter<P>(&mut self, chain: ChainId, emitter: [u8; 32]) -> &mut Self
+    where
+        P: TypePrefixedPayload + fmt::Debug + Send + Sync + 'static,
+    {
+        self.register_emitter_with(chain, emitter, |buf| {
+            P::read_slice(buf).ok().map(|p| Box::new(Plain(p)) as _)
+        })
+    }
+
+    /// Decode payloads from this emitter as `P`, with [`DynPayload::to_json`] support.
+    #[cfg(feature = "serde")]
+    pub fn register_emitter_serde<P>(&mut self, chain: ChainId, emitter: [u8; 32]) -> &mut Self
+    where
+        P: TypePrefixedPayload + serde::Serialize + fmt::Debug + Send + Sync + 'static,
+    {
+        self.register_emitter_with(chain, emitter, |buf| {
+            P::read_slice(buf)
+                .ok()
+                .map(|p| Box::new(Serializable(p)) as _)
+        })
+    }
+
+    /// Decode payloads from this emitter with a custom decoder.
+    pub fn register_emitter_with<F>(&mut self, chain: ChainId, emitter: [u8; 32], f: F) -> &mut Self
+    where
+        F: Fn(&[u8]) -> Option<Box<dyn DynPayload>> + Send + Sync + 'static,
+    {
+        self.emitters.insert((chain, emitter), Box::new(f));
+        self
+    }
+
+    /// Decode governance messages for this module as [`GovernanceMessage<P>`].
+    pub fn register_governance<P>(&mut self, module: [u8; 32]) -> &mut Self
+    where
+        P: TypePrefixedPayload + fmt::Debug + Send + Sync + 'static,
+    {
+        self.register_governance_with(module, |buf| {
+            GovernanceMessage::<P>::read_slice(buf)
+                .ok()
+                .map(|p| Box::new(Plain(p)) as _)
+        })
+    }
+
+    /// Decode governance messages for this module with a custom decoder. The decoder is given
+    /// the whole payload, including the module.
+    pub fn register_governance_with<F>(&mut self, module: [u8; 32], f: F) -> &mut Self
+    where
+        F: Fn(&[u8]) -> Option<Box<dyn DynPayload>> + Send + Sync + 'static,
+    {
+        self.governance.insert(module, Box::new(f));
+        self
+    }
+
+    /// Decode the payload with the decoder registered for its emitter or governance module.
+    ///
+    /// Returns `None` if no decoder is registered or the registered decoder rejects the
+    /// payload.
+    pub fn decode_registered(&self, body: &VaaBody) -> Option<Box<dyn DynPayload>> {
+        let payload = body.payload_bytes()?;
+
+        let decoder = if body.emitter_chain == gov::GOVERNANCE_CHAIN
+            && body.emitter_address == gov::GOVERNANCE_EMITTER
+        {
+            let module: [u8; 32] = payload.get(..32)?.try_into().unwrap();
+            self.governance.get(&module)?
+        } else {
+            self.emitters
+                .get(&(body.emitter_chain, body.emitter_address))?
+        };
+        decoder(payload)
+    }
+
+    /// Decode the payload with the decoder registered for its emitter or governance module,
+    /// falling back to [`KnownPayload::decode`].
+    pub fn decode(&self, body: &VaaBody) -> Box<dyn DynPayload> {
+        self.decode_registered(body)
+            .unwrap_or_else(|| Box::new(KnownPayload::decode(body)))
+    }
+}
+
+impl VaaBody {
+    /// Decode the payload using the decoders in `registry`, falling back to
+    /// [`KnownPayload::decode`].
+    pub fn read_payload_with(&self, registry: &PayloadRegistry) -> Box<dyn DynPayload> {
+        registry.decode(self)
+    }
+
+    /// Decode the payload with the decoder the [global
+    /// registry](PayloadRegistry::install_global) has for its emitter or governance module, see
+    /// [`PayloadRegistry::decode_registered`].
+    ///
+    /// Returns `None` if no registry is installed, it has no decoder for the payload, or the
+    /// decoder rejects it.
+    pub fn decode_registered(&self) -> Option<Box<dyn DynPayload>> {
+        PayloadRegistry::global()?.decode_registered(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use wormhole_io::{deploys::KnownChainId, Readable, Writeable};
+
+    use super::*;
+    use crate::payloads::{gov::GovernanceHeader, PayloadKind};
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+    struct Ping {
+        nonce: u32,
+    }
+
+    impl Readable for Ping {
+        const SIZE: Option<usize> = Some(4);
+
+        fn read<R>(reader: &mut R) -> std::io::Result<Self>
+        where
+            R: std::io::Read,
+        {
+            Ok(Self {
+                nonce: Readable::read(reader)?,
+            })
+        }
+    }
+
+    impl Writeable for Ping {
+        fn write<W>(&self, writer: &mut W) -> std::io::Result<()>
+        where
+            W: std::io::Write,
+        {
+            self.nonce.write(writer)
+        }
+
+        fn written_size(&self) -> usize {
+            4
+        }
+    }
+
+    impl TypePrefixedPayload for Ping {
+        const TYPE: &[u8] = &[0x42];
+    }
+
+    const EMITTER: [u8; 32] = [7; 32];
+    const MODULE: [u8; 32] = *b"\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Ping";
+
+    fn body(emitter_chain: KnownChainId, emitter_address: [u8; 32], payload: Vec<u8>) -> VaaBody {
+        VaaBody {
+            timestamp: 0,
+            nonce: 0,
+            emitter_chain: ChainId::Known(emitter_chain),
+            emitter_address,
+            sequence: 0,
+            consistency_level: 0,
+            payload: PayloadKind::Binary(payload),
+        }
+    }
+
+    #[test]
+    fn emitter() {
+        let mut registry = PayloadRegistry::new();
+        registry.register_emitter::<Ping>(ChainId::Known(KnownChainId::Ethereum), EMITTER);
+
+        let ping = Ping { nonce: 5 };
+        let decoded = body(KnownChainId::Ethereum, EMITTER, ping.to_payload_vec())
+            .read_payload_with(&registry);
+        assert_eq!(decoded.downcast_ref::<Ping>(), Some(&ping));
+        assert_eq!(format!("{decoded:?}"), "Ping { nonce: 5 }");
+
+        // Other emitters fall back to the built-in decoders.
+        let decoded =
+            body(KnownChainId::Solana, EMITTER, ping.to_payload_vec()).read_payload_with(&registry);
+        assert_eq!(
+            decoded.downcast_ref::<KnownPayload>(),
+            Some(&KnownPayload::Unknown(ping.to_payload_vec()))
+        );
+
+        // A registered decoder which rejects the payload also falls back.
+        let decoded =
+            body(KnownChainId::Ethereum, EMITTER, vec![0x42]).read_payload_with(&registry);
+        assert!(decoded.downcast_ref::<KnownPayload>().is_some());
+    }
+
+    #[test]
+    fn governance() {
+        let mut registry = PayloadRegistry::new();
+        registry.register_governance::<Ping>(MODULE);
+
+        let message = GovernanceMessage {
+            header: GovernanceHeader { module: MODULE },
+            decree: Ping { nonce: 1 },
+        };
+        let decoded = body(
+            KnownChainId::Solana,
+            gov::GOVERNANCE_EMITTER,
+            message.to_payload_vec(),
+        )
+        .read_payload_with(&registry);
+        assert_eq!(decoded.downcast_ref(), Some(&message));
+
+        // Only the governance emitter may send governance messages.
+        assert!(registry
+            .decode_registered(&body(
+                KnownChainId::Solana,
+                EMITTER,
+                message.to_payload_vec()
+            ))
+            .is_none());
+    }
+
+    #[test]
+    fn global() {
+        // Emitters of the global registry must not be used by any other test.
+        const GLOBAL_EMITTER: [u8; 32] = [9; 32];
+
+        let mut registry = PayloadRegistry::new();
+        registry.register_emitter_with(
+            ChainId::Known(KnownChainId::Ethereum),
+            GLOBAL_EMITTER,
+            |buf| {
+                Some(Box::new(Plain(Ping {
+                    nonce: buf.len() as u32,
+                })) as _)
+            },
+        );
+        registry.install_global().unwrap();
+        assert!(PayloadRegistry::new().install_global().is_err());
+
+        let short = body(KnownChainId::Ethereum, GLOBAL_EMITTER, vec![1, 2, 3]);
+        let decoded = short.decode_registered().unwrap();
+        assert_eq!(decoded.downcast_ref(), Some(&Ping { nonce: 3 }));
+        // Reading a payload type directly never goes through the registry.
+        assert_eq!(short.read_payload::<Ping>(), None);
+        let ping = Ping { nonce: 7 };
+        let full = body(
+            KnownChainId::Ethereum,
+            GLOBAL_EMITTER,
+            ping.to_payload_vec(),
+        );
+        assert_eq!(full.read_payload(), Some(ping));
+
+        let other = body(KnownChainId::Solana, GLOBAL_EMITTER, vec![1]);
+        assert!(other.decode_registered().is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn to_json() {
+        let mut registry = PayloadRegistry::new();
+        registry.register_emitter_serde::<Ping>(ChainId::Known(KnownChainId::Ethereum), EMITTER);
+
+        let decoded = body(
+            KnownChainId::Ethereum,
+            EMITTER,
+            Ping { nonce: 5 }.to_payload_vec(),
+        )
+        .read_payload_with(&registry);
+        assert_eq!(decoded.to_json(), Some(serde_json::json!({ "nonce": 5 })));
+
+        // The built-in fallback serializes too.
+        let decoded = body(KnownChainId::Solana, EMITTER, vec![1]).read_payload_with(&registry);
+        assert_eq!(
+            decoded.to_json(),
+            Some(serde_json::json!({ "Unknown": "0x01" }))
+        );
+    }
+}
@@ -5,16 +5,42 @@ use crate::{Readable, TypePrefixedPayload, Writeable};
 use std::io;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Attestation {
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub token_address: [u8; 32],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub token_chain: ChainId,
     pub decimals: u8,
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub symbol: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub name: [u8; 32],
 }
 
 impl Attestation {
+    /// Attest an asset. The symbol and name are truncated to 32 bytes on a character boundary.
+    pub fn new(
+        token_chain: ChainId,
+        token_address: [u8; 32],
+        decimals: u8,
+        symbol: &str,
+        name: &str,
+    ) -> Self {
+        Self {
+            token_address,
+            token_chain,
+            decimals,
+            symbol: to_fixed32(symbol),
+            name: to_fixed32(name),
+        }
+    }
+
     pub fn symbol_string(&self) -> String {
         fixed32_to_string(self.symbol)
     }
@@ -74,6 +100,22 @@ fn fixed32_to_string(fixed: [u8; 32]) -> String {
     String::from_utf8_lossy(&fixed[..idx]).into_owned()
 }
 
+fn to_fixed32(s: &str) -> [u8; 32] {
+    let s = truncate_utf8(s, 32);
+    let mut out = [0; 32];
+    out[..s.len()].copy_from_slice(s.as_bytes());
+    out
+}
+
+/// The longest prefix of `s` of at most `max` bytes which ends on a character boundary.
+pub(super) fn truncate_utf8(s: &str, max: usize) -> &str {
+    if s.len() <= max {
+        return s;
+    }
+    let end = (0..=max).rev().find(|&i| s.is_char_boundary(i)).unwrap();
+    &s[..end]
+}
+
 #[cfg(test)]
 mod test {
     use hex_literal::hex;
@@ -7,11 +7,46 @@ pub use transfer::Transfer;
 mod transfer_with_message;
 pub use transfer_with_message::TransferWithMessage;
 
+mod redeem;
+pub use redeem::{RedeemError, RedeemTransferWithMessage};
+
+mod wrapped_asset;
+pub use wrapped_asset::WrappedAssetInfo;
+
+use wormhole_io::deploys::{ChainId, KnownChainId};
+pub use wormhole_raw_vaas::support::{
+    wrapped_decimals, NormalizeError, Normalized, NormalizedTransfer, Normalizer, MAX_DECIMALS,
+};
+
 use crate::payloads::{Readable, TypePrefixedPayload, Writeable};
 
-// TODO: make normalizer struct for norm amount/relayer_fee.
+/// The most decimals a wrapped asset may have on `chain`, or `None` if the chain's token bridge
+/// creates wrapped assets with the attested decimals.
+///
+/// Solana (`WRAPPED_MINT_MAX_DECIMALS`), Sui, Aptos, Algorand and the CosmWasm token bridges
+/// cap wrapped assets at [`MAX_DECIMALS`]. The EVM token bridge does not.
+pub const fn wrapped_max_decimals(chain: ChainId) -> Option<u8> {
+    use KnownChainId::*;
+
+    match chain {
+        ChainId::Known(
+            Solana | Pythnet | Sui | Aptos | Algorand | Terra | Terra2 | Injective | Xpla | Sei,
+        ) => Some(MAX_DECIMALS),
+        _ => None,
+    }
+}
+
+/// Normalizer for an asset with `source_decimals` redeemed on `target_chain`, where it is
+/// either the wrapped asset or a native asset with the same decimals.
+pub const fn normalizer_for(source_decimals: u8, target_chain: ChainId) -> Normalizer {
+    match wrapped_max_decimals(target_chain) {
+        Some(max) => Normalizer::wrapped(source_decimals, max),
+        None => Normalizer::new(source_decimals, source_decimals),
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TokenBridgeMessage {
     Transfer(Transfer),
     Attestation(Attestation),
@@ -68,3 +103,25 @@ impl Writeable for TokenBridgeMessage {
 impl TypePrefixedPayload for TokenBridgeMessage {
     const TYPE: &[u8] = &[];
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::U256;
+
+    #[test]
+    fn wrapped_normalizer() {
+        let solana = normalizer_for(18, ChainId::Known(KnownChainId::Solana));
+        assert_eq!(solana, Normalizer::new(18, 8));
+        let ethereum = normalizer_for(18, ChainId::Known(KnownChainId::Ethereum));
+        assert_eq!(ethereum, Normalizer::new(18, 18));
+
+        let amount = ethereum.normalize(U256::from(1_500_000_000_000_000_001u64));
+        assert_eq!(amount.encoded, U256::from(150_000_000));
+        assert_eq!(solana.denormalize(amount.encoded), Some(amount.encoded));
+        assert_eq!(
+            ethereum.denormalize(amount.encoded),
+            Some(U256::from(1_500_000_000_000_000_000u64))
+        );
+    }
+}
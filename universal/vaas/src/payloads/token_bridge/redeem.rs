@@ -0,0 +1,213 @@
+use std::fmt;
+
+use wormhole_io::deploys::{ChainId, CoreDeployment};
+
+use crate::{payloads::token_bridge::TransferWithMessage, TypePrefixedPayload};
+
+/// Why a transfer with message cannot be redeemed by the caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RedeemError {
+    /// The local chain has no token bridge to redeem the transfer with.
+    NoTokenBridge(ChainId),
+    /// The transfer is addressed to another chain.
+    InvalidRedeemerChain { expected: ChainId, found: ChainId },
+    /// The transfer is addressed to another redeemer.
+    InvalidRedeemer { expected: [u8; 32], found: [u8; 32] },
+    /// The inner payload is not the expected message.
+    InvalidPayload,
+}
+
+impl fmt::Display for RedeemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoTokenBridge(chain) => write!(f, "no token bridge on chain {}", chain.to_u16()),
+            Self::InvalidRedeemerChain { expected, found } => write!(
+                f,
+                "transfer redeemable on chain {}, not {}",
+                found.to_u16(),
+                expected.to_u16()
+            ),
+            Self::InvalidRedeemer { .. } => write!(f, "caller is not the transfer's redeemer"),
+            Self::InvalidPayload => write!(f, "invalid transfer message payload"),
+        }
+    }
+}
+
+impl std::error::Error for RedeemError {}
+
+/// Redemption checks for token bridge transfers with message (payload 3), implemented for the
+/// owned [`TransferWithMessage`] and the zero-copy
+/// [`wormhole_raw_vaas::token_bridge::TransferWithMessage`].
+///
+/// Only the redeemer may redeem these transfers, so integrators must check both the redeemer
+/// chain and address before acting on the inner message. The local chain is taken from its
+/// [`CoreDeployment`], which must have a token bridge.
+pub trait RedeemTransferWithMessage {
+    /// Chain the transfer is redeemable on.
+    fn redeemer_chain_id(&self) -> ChainId;
+
+    /// Address of the redeemer on its chain.
+    fn redeemer_address(&self) -> [u8; 32];
+
+    /// The application message carried with the transfer.
+    fn message_bytes(&self) -> &[u8];
+
+    /// Check that the transfer is redeemable by `redeemer` on the chain of `deployment`.
+    fn check_redeemer(
+        &self,
+        deployment: &CoreDeployment,
+        redeemer: [u8; 32],
+    ) -> Result<(), RedeemError> {
+        let chain = deployment.chain_id;
+        if deployment.token_bridge_address.is_none() {
+            return Err(RedeemError::NoTokenBridge(chain));
+        }
+
+        let found = self.redeemer_chain_id();
+        if found != chain {
+            return Err(RedeemError::InvalidRedeemerChain {
+                expected: chain,
+                found,
+            });
+        }
+
+        let found = self.redeemer_address();
+        if found != redeemer {
+            return Err(RedeemError::InvalidRedeemer {
+                expected: redeemer,
+                found,
+            });
+        }
+        Ok(())
+    }
+
+    /// Decode the carried message, which must be exactly one `P`.
+    fn read_message<P: TypePrefixedPayload>(&self) -> Result<P, RedeemError> {
+        P::read_slice(self.message_bytes()).map_err(|_| RedeemError::InvalidPayload)
+    }
+
+    /// Check that the transfer is redeemable by `redeemer` on the chain of `deployment`, and
+    /// decode the carried message.
+    fn redeem<P: TypePrefixedPayload>(
+        &self,
+        deployment: &CoreDeployment,
+        redeemer: [u8; 32],
+    ) -> Result<P, RedeemError> {
+        self.check_redeemer(deployment, redeemer)?;
+        self.read_message()
+    }
+}
+
+impl RedeemTransferWithMessage for TransferWithMessage {
+    fn redeemer_chain_id(&self) -> ChainId {
+        self.redeemer_chain
+    }
+
+    fn redeemer_address(&self) -> [u8; 32] {
+        self.redeemer
+    }
+
+    fn message_bytes(&self) -> &[u8] {
+        &self.payload
+    }
+}
+
+impl RedeemTransferWithMessage for wormhole_raw_vaas::token_bridge::TransferWithMessage<'_> {
+    fn redeemer_chain_id(&self) -> ChainId {
+        self.redeemer_chain().into()
+    }
+
+    fn redeemer_address(&self) -> [u8; 32] {
+        self.redeemer()
+    }
+
+    fn message_bytes(&self) -> &[u8] {
+        self.payload().span()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use wormhole_io::deploys::{deploys::MAINNETS_BY_ID, KnownChainId};
+
+    use super::*;
+    use crate::{payloads::token_bridge::Transfer, EncodedAmount, Writeable};
+
+    const REDEEMER: [u8; 32] = [2; 32];
+    const CHAIN: ChainId = ChainId::Known(KnownChainId::Base);
+
+    fn deployment(chain: KnownChainId) -> &'static CoreDeployment {
+        MAINNETS_BY_ID.get(&chain).unwrap()
+    }
+
+    // Any type prefixed payload will do as the inner message.
+    fn inner() -> Transfer {
+        Transfer {
+            norm_amount: EncodedAmount::from(5u64),
+            token_address: [4; 32],
+            token_chain: ChainId::Known(KnownChainId::Solana),
+            recipient: [5; 32],
+            recipient_chain: ChainId::Known(KnownChainId::Sui),
+            norm_relayer_fee: EncodedAmount::ZERO,
+        }
+    }
+
+    fn transfer(payload: Vec<u8>) -> TransferWithMessage {
+        TransferWithMessage {
+            norm_amount: EncodedAmount::from(100u64),
+            token_address: [1; 32],
+            token_chain: ChainId::Known(KnownChainId::Ethereum),
+            redeemer: REDEEMER,
+            redeemer_chain: CHAIN,
+            sender: [3; 32],
+            payload,
+        }
+    }
+
+    #[test]
+    fn owned_and_raw() {
+        let owned = transfer(inner().to_payload_vec());
+        let encoded = owned.to_vec();
+        let raw = wormhole_raw_vaas::token_bridge::TransferWithMessage::parse(&encoded).unwrap();
+
+        let base = deployment(KnownChainId::Base);
+        assert_eq!(owned.redeem::<Transfer>(base, REDEEMER), Ok(inner()));
+        assert_eq!(raw.redeem::<Transfer>(base, REDEEMER), Ok(inner()));
+
+        assert_eq!(
+            raw.check_redeemer(deployment(KnownChainId::Ethereum), REDEEMER),
+            Err(RedeemError::InvalidRedeemerChain {
+                expected: ChainId::Known(KnownChainId::Ethereum),
+                found: CHAIN,
+            })
+        );
+        assert_eq!(
+            owned.check_redeemer(base, [9; 32]),
+            Err(RedeemError::InvalidRedeemer {
+                expected: [9; 32],
+                found: REDEEMER,
+            })
+        );
+        assert_eq!(
+            owned.check_redeemer(deployment(KnownChainId::Gnosis), REDEEMER),
+            Err(RedeemError::NoTokenBridge(ChainId::Known(
+                KnownChainId::Gnosis
+            )))
+        );
+    }
+
+    #[test]
+    fn invalid_payload() {
+        let mut payload = inner().to_payload_vec();
+        payload.push(0);
+        assert_eq!(
+            transfer(payload).redeem::<Transfer>(deployment(KnownChainId::Base), REDEEMER),
+            Err(RedeemError::InvalidPayload)
+        );
+        assert_eq!(
+            transfer(vec![]).read_message::<Transfer>(),
+            Err(RedeemError::InvalidPayload)
+        );
+    }
+}
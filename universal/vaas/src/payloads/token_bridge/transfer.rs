@@ -5,11 +5,23 @@ use crate::{EncodedAmount, Readable, TypePrefixedPayload, Writeable};
 use std::io;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Transfer {
     pub norm_amount: EncodedAmount,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub token_address: [u8; 32],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub token_chain: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub recipient: [u8; 32],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub recipient_chain: ChainId,
     pub norm_relayer_fee: EncodedAmount,
 }
@@ -103,13 +115,11 @@ mod tests {
                     norm_amount: EncodedAmount::from(10000000000u64),
                     token_address: hex!(
                         "165809739240a0ac03b98440fe8985548e3aa683cd0d4d9df5b5659669faa301"
-                    )
-                    .into(),
+                    ),
                     token_chain: 1.into(),
                     recipient: hex!(
                         "000000000000000000000000c10820983f33456ce7beb3a046f5a83fa34f027d"
-                    )
-                    .into(),
+                    ),
                     recipient_chain: 2.into(),
                     norm_relayer_fee: EncodedAmount::ZERO,
                 }
@@ -171,4 +181,31 @@ mod tests {
             panic!("wrong message type");
         }
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let transfer = Transfer {
+            norm_amount: EncodedAmount::from(4100000000u64),
+            token_address: hex!("069b8857feab8184fb687f634618c035dac439dc1aeb3b5598a0f00000000001"),
+            token_chain: ChainId::Known(KnownChainId::Solana),
+            recipient: hex!("000000000000000000000000efd4aa8f954ebdea82b8757c029fc8475a45e9cd"),
+            recipient_chain: ChainId::from_u16(2),
+            norm_relayer_fee: EncodedAmount::ZERO,
+        };
+
+        let json = serde_json::to_value(&transfer).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "normAmount": "4100000000",
+                "tokenAddress": "0x069b8857feab8184fb687f634618c035dac439dc1aeb3b5598a0f00000000001",
+                "tokenChain": "Solana",
+                "recipient": "0x000000000000000000000000efd4aa8f954ebdea82b8757c029fc8475a45e9cd",
+                "recipientChain": "Ethereum",
+                "normRelayerFee": "0",
+            })
+        );
+        assert_eq!(serde_json::from_value::<Transfer>(json).unwrap(), transfer);
+    }
 }
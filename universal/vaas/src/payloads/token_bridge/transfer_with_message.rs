@@ -5,13 +5,27 @@ use crate::{EncodedAmount, Readable, TypePrefixedPayload, Writeable};
 use std::io;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TransferWithMessage {
     pub norm_amount: EncodedAmount,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub token_address: [u8; 32],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub token_chain: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub redeemer: [u8; 32],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "wormhole_io::deploys::chain_id::serde::name")
+    )]
     pub redeemer_chain: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub sender: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_bytes"))]
     pub payload: Vec<u8>,
 }
 
@@ -0,0 +1,142 @@
+use wormhole_io::deploys::{ChainId, KnownChainId};
+
+use crate::payloads::token_bridge::{
+    attestation::truncate_utf8, wrapped_max_decimals, Attestation, Normalizer,
+};
+
+/// Metadata of the wrapped asset a token bridge creates on `target_chain` from an
+/// [`Attestation`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WrappedAssetInfo {
+    /// Chain of the original asset.
+    pub token_chain: ChainId,
+    /// Address of the original asset on its chain.
+    pub token_address: [u8; 32],
+    /// Chain the wrapped asset lives on.
+    pub target_chain: ChainId,
+    /// Decimals of the original asset.
+    pub native_decimals: u8,
+    /// Decimals of the wrapped asset, capped for chains which limit wrapped decimals.
+    pub decimals: u8,
+    pub symbol: String,
+    pub name: String,
+}
+
+impl WrappedAssetInfo {
+    pub fn new(attestation: &Attestation, target_chain: ChainId) -> Self {
+        let decimals = match wrapped_max_decimals(target_chain) {
+            Some(max) => attestation.decimals.min(max),
+            None => attestation.decimals,
+        };
+        let (max_symbol, max_name) = max_metadata_lens(target_chain);
+
+        Self {
+            token_chain: attestation.token_chain,
+            token_address: attestation.token_address,
+            target_chain,
+            native_decimals: attestation.decimals,
+            decimals,
+            symbol: truncate_utf8(&attestation.symbol_string(), max_symbol).to_owned(),
+            name: truncate_utf8(&attestation.name_string(), max_name).to_owned(),
+        }
+    }
+
+    /// The original asset, which uniquely identifies the wrapped asset on its chain.
+    pub fn origin(&self) -> (ChainId, [u8; 32]) {
+        (self.token_chain, self.token_address)
+    }
+
+    /// Normalizer for transfers of the original asset redeemed as this wrapped asset.
+    pub fn normalizer(&self) -> Normalizer {
+        Normalizer::new(self.native_decimals, self.decimals)
+    }
+
+    /// The attestation for the original asset, with the wrapped symbol and name.
+    pub fn to_attestation(&self) -> Attestation {
+        Attestation::new(
+            self.token_chain,
+            self.token_address,
+            self.native_decimals,
+            &self.symbol,
+            &self.name,
+        )
+    }
+}
+
+impl From<&WrappedAssetInfo> for Attestation {
+    fn from(info: &WrappedAssetInfo) -> Self {
+        info.to_attestation()
+    }
+}
+
+/// Maximum byte lengths of a wrapped asset's symbol and name on `chain`.
+fn max_metadata_lens(chain: ChainId) -> (usize, usize) {
+    match chain {
+        // Metaplex token metadata limits.
+        ChainId::Known(KnownChainId::Solana | KnownChainId::Pythnet) => (10, 32),
+        _ => (32, 32),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use hex_literal::hex;
+
+    use super::*;
+
+    const WETH: [u8; 32] = hex!("000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+
+    fn weth() -> Attestation {
+        Attestation::new(
+            ChainId::Known(KnownChainId::Ethereum),
+            WETH,
+            18,
+            "WETH",
+            "Wrapped Ether",
+        )
+    }
+
+    #[test]
+    fn solana() {
+        let info = WrappedAssetInfo::new(&weth(), ChainId::Known(KnownChainId::Solana));
+        assert_eq!(
+            info.origin(),
+            (ChainId::Known(KnownChainId::Ethereum), WETH)
+        );
+        assert_eq!(info.native_decimals, 18);
+        assert_eq!(info.decimals, 8);
+        assert_eq!(info.normalizer(), Normalizer::new(18, 8));
+        assert_eq!(info.symbol, "WETH");
+        assert_eq!(info.name, "Wrapped Ether");
+        assert_eq!(info.to_attestation(), weth());
+    }
+
+    #[test]
+    fn evm() {
+        let info = WrappedAssetInfo::new(&weth(), ChainId::Known(KnownChainId::Arbitrum));
+        assert_eq!(info.decimals, 18);
+        assert_eq!(Attestation::from(&info), weth());
+    }
+
+    #[test]
+    fn truncation() {
+        // 33 bytes, the last character split by the 32 byte limit.
+        let name = "0000000000000000000000000000000🔥";
+        let attestation = Attestation::new(
+            ChainId::Known(KnownChainId::Sui),
+            [1; 32],
+            9,
+            "🔥🔥🔥",
+            name,
+        );
+        assert_eq!(attestation.name_string(), "0000000000000000000000000000000");
+
+        let mut split = attestation.clone();
+        split.name.copy_from_slice(&name.as_bytes()[..32]);
+        let info = WrappedAssetInfo::new(&split, ChainId::Known(KnownChainId::Solana));
+        assert_eq!(info.name, "0000000000000000000000000000000");
+        // Metaplex symbols are at most 10 bytes.
+        assert_eq!(info.symbol, "🔥🔥");
+        assert_eq!(info.decimals, 8);
+    }
+}
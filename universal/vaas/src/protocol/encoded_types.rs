@@ -1,12 +1,10 @@
 use ruint::UintTryFrom;
+use wormhole_raw_vaas::support::Normalizer;
 
 use crate::{utils::U256, Readable, Writeable};
 
 use std::io;
 
-const MAX_DECIMALS: u8 = 8;
-const TEN: U256 = U256::from_limbs([10, 0, 0, 0]);
-
 /// This amount reflects the token transfer amount encoded in a Token Bridge
 /// message. These amounts are capped at 8 decimals. This means that any amount
 /// of a coin whose metadata defines its decimals as some value greater than 8,
@@ -14,7 +12,14 @@ const TEN: U256 = U256::from_limbs([10, 0, 0, 0]);
 /// some residual amount after the transfer). For inbound transfers, this amount
 /// will be denormalized (scaled by the same decimal difference).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct EncodedAmount(pub U256);
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct EncodedAmount(
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::decimal"))] pub U256,
+);
 
 impl EncodedAmount {
     pub const ZERO: Self = Self(U256::ZERO);
@@ -23,32 +28,24 @@ impl EncodedAmount {
     /// asset's decimals only if the decimals exceed the maximum allowed (8)
     /// for encoding.
     pub fn norm(amount: U256, decimals: u8) -> Self {
-        if decimals <= MAX_DECIMALS {
-            Self(amount)
-        } else {
-            Self(amount.wrapping_div(pow10(decimals - MAX_DECIMALS)))
-        }
+        Self(
+            Normalizer::new(decimals, decimals)
+                .normalize(amount)
+                .encoded,
+        )
     }
 
     /// Convert an encoded amount back to a raw amount by scaling it by its
     /// decimals if the decimals eceed the maximum allowed (8) from encoding.
     pub fn denorm(self, decimals: u8) -> U256 {
-        if decimals <= MAX_DECIMALS {
-            self.0
-        } else {
-            self.0.wrapping_mul(pow10(decimals - MAX_DECIMALS))
-        }
+        Normalizer::new(decimals, decimals).wrapping_denormalize(self.0)
     }
 
     /// Convert an encoded amount back to a raw amount by scaling it by its
     /// decimals if the decimals eceed the maximum allowed (8) from encoding.
     /// This method will return `None` if the raw amount overflows 32 bytes.
     pub fn checked_denorm(self, decimals: u8) -> Option<U256> {
-        if decimals <= MAX_DECIMALS {
-            Some(self.0)
-        } else {
-            self.0.checked_mul(pow10(decimals - MAX_DECIMALS))
-        }
+        Normalizer::new(decimals, decimals).denormalize(self.0)
     }
 }
 
@@ -87,15 +84,12 @@ impl Writeable for EncodedAmount {
     }
 }
 
-fn pow10(power: u8) -> U256 {
-    // TEN.pow(U256::from(U8::from(power)))
-    TEN.pow(U256::from(power))
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
 
+    const TEN: U256 = U256::from_limbs([10, 0, 0, 0]);
+
     const TRIAL_AMOUNTS: [U256; 3] = [
         U256::ZERO,
         U256::from_limbs([0xffffffffffffffff, 0, 0, 0]),
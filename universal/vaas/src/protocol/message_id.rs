@@ -0,0 +1,181 @@
+use std::{fmt, str::FromStr};
+
+use wormhole_io::deploys::ChainId;
+
+use crate::{support::hex, VaaBody};
+
+/// Uniquely identifies a Wormhole message by its emitter and sequence.
+///
+/// The canonical string form is `<chain>/<emitter>/<sequence>`, with the chain and sequence in
+/// decimal and the emitter as 64 lowercase hex characters. This is the format the guardians and
+/// the Wormholescan API use for VAA ids, e.g.
+/// `4/000000000000000000000000b6f6d86a8f9879a9c87f643768d9efc38c1da6e7/276319`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MessageId {
+    pub chain: ChainId,
+    pub emitter: [u8; 32],
+    pub sequence: u64,
+}
+
+impl MessageId {
+    pub const fn new(chain: ChainId, emitter: [u8; 32], sequence: u64) -> Self {
+        Self {
+            chain,
+            emitter,
+            sequence,
+        }
+    }
+}
+
+impl fmt::Display for MessageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/", self.chain.to_u16())?;
+        for byte in self.emitter {
+            write!(f, "{byte:02x}")?;
+        }
+        write!(f, "/{}", self.sequence)
+    }
+}
+
+/// Error returned when a string is not a valid [`MessageId`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseMessageIdError(&'static str);
+
+impl fmt::Display for ParseMessageIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid message id: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseMessageIdError {}
+
+impl FromStr for MessageId {
+    type Err = ParseMessageIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('/');
+        let (Some(chain), Some(emitter), Some(sequence), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(ParseMessageIdError("expected <chain>/<emitter>/<sequence>"));
+        };
+
+        let chain = chain
+            .parse::<u16>()
+            .map_err(|_| ParseMessageIdError("invalid chain"))?;
+        let emitter = hex::decode_array(emitter).ok_or(ParseMessageIdError("invalid emitter"))?;
+        let sequence = sequence
+            .parse()
+            .map_err(|_| ParseMessageIdError("invalid sequence"))?;
+
+        Ok(Self::new(chain.into(), emitter, sequence))
+    }
+}
+
+impl From<&VaaBody> for MessageId {
+    fn from(body: &VaaBody) -> Self {
+        Self::new(body.emitter_chain, body.emitter_address, body.sequence)
+    }
+}
+
+impl From<wormhole_raw_vaas::Body<'_>> for MessageId {
+    fn from(body: wormhole_raw_vaas::Body<'_>) -> Self {
+        Self::new(
+            body.emitter_chain().into(),
+            body.emitter_address(),
+            body.sequence(),
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MessageId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MessageId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use hex_literal::hex;
+    use wormhole_io::deploys::KnownChainId;
+
+    use super::*;
+    use crate::{Readable, Vaa};
+
+    const ID: &str = "4/000000000000000000000000b6f6d86a8f9879a9c87f643768d9efc38c1da6e7/276319";
+
+    #[test]
+    fn round_trip() {
+        let id: MessageId = ID.parse().unwrap();
+        assert_eq!(
+            id,
+            MessageId::new(
+                ChainId::Known(KnownChainId::Bsc),
+                hex!("000000000000000000000000b6f6d86a8f9879a9c87f643768d9efc38c1da6e7"),
+                276319
+            )
+        );
+        assert_eq!(id.to_string(), ID);
+
+        let upper = "4/0x000000000000000000000000B6F6D86A8F9879A9C87F643768D9EFC38C1DA6E7/276319";
+        assert_eq!(upper.parse::<MessageId>().unwrap(), id);
+    }
+
+    #[test]
+    fn invalid() {
+        for s in [
+            "",
+            "4",
+            "4/000000000000000000000000b6f6d86a8f9879a9c87f643768d9efc38c1da6e7",
+            "4/000000000000000000000000b6f6d86a8f9879a9c87f643768d9efc38c1da6e7/1/2",
+            "65536/000000000000000000000000b6f6d86a8f9879a9c87f643768d9efc38c1da6e7/1",
+            "4/b6f6d86a8f9879a9c87f643768d9efc38c1da6e7/1",
+            "4/000000000000000000000000b6f6d86a8f9879a9c87f643768d9efc38c1da6zz/1",
+            "4/000000000000000000000000b6f6d86a8f9879a9c87f643768d9efc38c1da6e7/-1",
+        ] {
+            assert!(s.parse::<MessageId>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        let a = MessageId::new(ChainId::Known(KnownChainId::Solana), [0xff; 32], 10);
+        let b = MessageId::new(ChainId::Known(KnownChainId::Ethereum), [0; 32], 0);
+        let c = MessageId::new(ChainId::Known(KnownChainId::Ethereum), [0; 32], 1);
+        let d = MessageId::new(ChainId::from_u16(29999), [0; 32], 0);
+        let mut ids = vec![d, c, b, a];
+        ids.sort();
+        assert_eq!(ids, vec![a, b, c, d]);
+    }
+
+    #[test]
+    fn from_bodies() {
+        let vaa = hex!("01000000000100ff7edcd3facb7dd6e06e0bd3e178cfddd775208f3e09f0b68bba981b812258716e6e5cd42c0ba413586df1e4066e29a1a41f9a49ae05a58f5fa93590d165abf100000000007ce2ea3f000195f83a27e90c622a98c037353f271fd8f5f57b4dc18ebf5ff75a934724bd0491a43a1c0020f88a3e2002000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc200021257455448000000000000000000000000000000000000000000000000000000005772617070656420657468657200000000000000000000000000000000000000");
+
+        let owned = MessageId::from(&Vaa::read(&mut vaa.as_slice()).unwrap().body);
+        let raw = MessageId::from(wormhole_raw_vaas::Vaa::parse(&vaa).unwrap().body());
+        assert_eq!(owned, raw);
+        assert_eq!(
+            owned.to_string(),
+            "1/95f83a27e90c622a98c037353f271fd8f5f57b4dc18ebf5ff75a934724bd0491/11833801757748136510"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let id: MessageId = ID.parse().unwrap();
+        let json = serde_json::to_value(id).unwrap();
+        assert_eq!(json, serde_json::json!(ID));
+        assert_eq!(serde_json::from_value::<MessageId>(json).unwrap(), id);
+    }
+}
@@ -1,5 +1,9 @@
 pub(crate) mod encoded_types;
 
+pub(crate) mod message_id;
+
 pub(crate) mod signature;
 
+pub(crate) mod signing;
+
 pub(crate) mod vaa;
@@ -7,7 +7,10 @@ pub struct GuardianSetSig {
     pub guardian_set_index: u8,
     #[cfg_attr(
         feature = "serde",
-        serde(with = "crate::support::serde::fixed_bytes_as_array")
+        serde(
+            serialize_with = "crate::support::serde::hex_array::serialize_unprefixed",
+            deserialize_with = "crate::support::serde::hex_array::deserialize"
+        )
     )]
     pub signature: [u8; 65],
 }
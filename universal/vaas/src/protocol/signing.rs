@@ -0,0 +1,84 @@
+#![cfg(feature = "k256")]
+
+use std::fmt;
+
+use k256::ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey};
+
+use crate::{utils, GuardianSetSig, Vaa, VaaBody, VaaHash, VaaHeader};
+
+impl VaaBody {
+    /// Sign the body with guardian keys, producing a VAA for the given guardian set.
+    ///
+    /// `guardians` are in guardian set order, so the key at position `i` signs as guardian `i`.
+    /// Fails if there are more guardians than a guardian set index can address.
+    pub fn sign(
+        self,
+        guardian_set_index: u32,
+        guardians: &[SigningKey],
+    ) -> Result<Vaa, TooManyGuardiansError> {
+        if guardians.len() > usize::from(u8::MAX) + 1 {
+            return Err(TooManyGuardiansError(guardians.len()));
+        }
+
+        let digest = self.double_digest();
+        let signatures = guardians
+            .iter()
+            .enumerate()
+            .map(|(index, key)| {
+                let (sig, recovery_id) = key
+                    .sign_prehash_recoverable(&digest.0)
+                    .expect("32-byte prehash is always signable");
+
+                let mut signature = [0; 65];
+                signature[..64].copy_from_slice(&sig.to_bytes());
+                signature[64] = recovery_id.to_byte();
+                GuardianSetSig {
+                    guardian_set_index: index as u8,
+                    signature,
+                }
+            })
+            .collect();
+
+        Ok(Vaa {
+            header: VaaHeader {
+                version: 1,
+                guardian_set_index,
+                signatures,
+            },
+            body: self,
+        })
+    }
+}
+
+/// Error returned when signing with more guardians than fit in a guardian set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyGuardiansError(usize);
+
+impl fmt::Display for TooManyGuardiansError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} guardians, at most 256 allowed", self.0)
+    }
+}
+
+impl std::error::Error for TooManyGuardiansError {}
+
+impl GuardianSetSig {
+    /// Recover the address of the guardian which produced this signature over `digest`.
+    ///
+    /// Returns `None` if the signature is malformed.
+    pub fn recover(&self, digest: &VaaHash) -> Option<[u8; 20]> {
+        let signature = Signature::from_slice(&self.raw_sig()).ok()?;
+        let recovery_id = RecoveryId::from_byte(self.recovery_id())?;
+        VerifyingKey::recover_from_prehash(&digest.0, &signature, recovery_id)
+            .ok()
+            .map(|key| guardian_address(&key))
+    }
+}
+
+/// The Ethereum-style address of a guardian key, as listed in a guardian set.
+pub fn guardian_address(key: &VerifyingKey) -> [u8; 20] {
+    let point = key.to_encoded_point(false);
+    utils::keccak256(&point.as_bytes()[1..])[12..]
+        .try_into()
+        .unwrap()
+}
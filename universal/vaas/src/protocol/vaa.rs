@@ -1,8 +1,10 @@
-use wormhole_io::deploys::ChainId;
+use wormhole_io::deploys::{
+    deploys::find_emitter, ChainId, ConsistencyLevel, Finality, KnownEmitter, NetEnv,
+};
 
 use crate::{
     payloads::{self, PayloadKind},
-    utils, TypePrefixedPayload,
+    utils, MessageId, TypePrefixedPayload,
 };
 pub use crate::{GuardianSetSig, Readable, Writeable};
 
@@ -73,7 +75,7 @@ impl Writeable for VaaHeader {
     }
 
     fn written_size(&self) -> usize {
-        1 + 4 + (self.signatures.len() * <GuardianSetSig as Readable>::SIZE.unwrap())
+        1 + 4 + 1 + (self.signatures.len() * <GuardianSetSig as Readable>::SIZE.unwrap())
     }
 }
 
@@ -119,7 +121,9 @@ pub struct VaaBody {
         serde(deserialize_with = "wormhole_io::deploys::chain_id::serde::deserialize_value")
     )]
     pub emitter_chain: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::hex_array"))]
     pub emitter_address: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::support::serde::decimal"))]
     pub sequence: u64,
     pub consistency_level: u8,
 
@@ -192,6 +196,26 @@ impl VaaBody {
         self.read_payload()
     }
 
+    /// The chain, emitter and sequence which uniquely identify this message.
+    pub fn message_id(&self) -> MessageId {
+        self.into()
+    }
+
+    /// The known contract of `net_env` which emitted this message, if any.
+    pub fn known_emitter(&self, net_env: NetEnv) -> Option<KnownEmitter> {
+        find_emitter(self.emitter_chain, &self.emitter_address, net_env)
+    }
+
+    /// The consistency level of this message, interpreted for its emitter chain.
+    pub fn consistency(&self) -> ConsistencyLevel {
+        ConsistencyLevel::new(self.emitter_chain, self.consistency_level)
+    }
+
+    /// How final the emitting block was when the guardians observed this message.
+    pub fn finality(&self) -> Finality {
+        self.consistency().finality()
+    }
+
     #[inline]
     pub fn digest(&self) -> MessageHash {
         MessageHash(utils::keccak256(self.to_vec()))
@@ -289,3 +313,96 @@ impl AsMut<[u8]> for VaaHash {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod test {
+    use hex_literal::hex;
+    use wormhole_io::deploys::{ChainId, ContractRole, Finality, KnownChainId, NetEnv};
+
+    use super::*;
+
+    fn body(emitter_chain: KnownChainId, consistency_level: u8) -> VaaBody {
+        VaaBody {
+            timestamp: 0,
+            nonce: 0,
+            emitter_chain: ChainId::Known(emitter_chain),
+            emitter_address: [0; 32],
+            sequence: 0,
+            consistency_level,
+            payload: PayloadKind::Binary(vec![]),
+        }
+    }
+
+    #[test]
+    fn finality() {
+        assert_eq!(
+            body(KnownChainId::Ethereum, 200).finality(),
+            Finality::Instant
+        );
+        assert_eq!(body(KnownChainId::Ethereum, 201).finality(), Finality::Safe);
+        assert_eq!(
+            body(KnownChainId::Ethereum, 1).finality(),
+            Finality::Finalized
+        );
+        assert_eq!(body(KnownChainId::Solana, 1).finality(), Finality::Instant);
+        assert_eq!(
+            body(KnownChainId::Solana, 32).finality(),
+            Finality::Finalized
+        );
+        assert_eq!(
+            body(KnownChainId::Solana, 200).finality(),
+            Finality::Finalized
+        );
+        assert_eq!(body(KnownChainId::Sui, 200).finality(), Finality::Finalized);
+
+        let vaa = hex!("01000000000100ff7edcd3facb7dd6e06e0bd3e178cfddd775208f3e09f0b68bba981b812258716e6e5cd42c0ba413586df1e4066e29a1a41f9a49ae05a58f5fa93590d165abf100000000007ce2ea3f000195f83a27e90c622a98c037353f271fd8f5f57b4dc18ebf5ff75a934724bd0491a43a1c0020f88a3e2002000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc200021257455448000000000000000000000000000000000000000000000000000000005772617070656420657468657200000000000000000000000000000000000000");
+        let encoded = vaa;
+        let vaa = Vaa::read(&mut encoded.as_slice()).unwrap();
+        assert_eq!(vaa.body.finality(), Finality::Finalized);
+        assert_eq!(vaa.written_size(), encoded.len());
+    }
+
+    #[test]
+    fn expected_latency() {
+        let instant = body(KnownChainId::Ethereum, 200).consistency();
+        let finalized = body(KnownChainId::Ethereum, 1).consistency();
+        assert!(instant.expected_latency().unwrap() < finalized.expected_latency().unwrap());
+
+        let unknown = ConsistencyLevel::new(ChainId::from_u16(u16::MAX), 200);
+        assert_eq!(unknown.finality(), Finality::Finalized);
+        assert_eq!(unknown.expected_latency(), None);
+    }
+
+    #[test]
+    fn known_emitter() {
+        let mut body = body(KnownChainId::Solana, 32);
+        body.emitter_address =
+            hex!("ec7372995d5cc8732397fb0ad35c0121e0eaa90d26f828a534cab54391b3a4f5");
+        let emitter = body.known_emitter(NetEnv::MainNet).unwrap();
+        assert_eq!(emitter.role, ContractRole::TokenBridge);
+        assert_eq!(emitter.deployment.name, "Solana");
+        assert!(matches!(emitter.deployment.net_env, NetEnv::MainNet));
+
+        body.emitter_chain = ChainId::Known(KnownChainId::Ethereum);
+        body.emitter_address =
+            hex!("0000000000000000000000003ee18b2214aff97000d974cf647e7c347e8fa585");
+        let emitter = body.known_emitter(NetEnv::MainNet).unwrap();
+        assert_eq!(emitter.role, ContractRole::TokenBridge);
+        assert_eq!(emitter.deployment.name, "Ethereum");
+
+        // Same address, wrong chain.
+        body.emitter_chain = ChainId::Known(KnownChainId::Bsc);
+        assert!(body.known_emitter(NetEnv::MainNet).is_none());
+
+        body.emitter_chain = ChainId::Known(KnownChainId::Ethereum);
+        body.emitter_address =
+            hex!("00000000000000000000000027428dd2d3dd32a4d7f7c497eaaa23130d894911");
+        let emitter = body.known_emitter(NetEnv::MainNet).unwrap();
+        assert_eq!(emitter.role, ContractRole::StandardRelayer);
+
+        body.emitter_address =
+            hex!("000000000000000000000000aada05bd399372f0b0463744c09113c137636f6a");
+        let emitter = body.known_emitter(NetEnv::MainNet).unwrap();
+        assert_eq!(emitter.role, ContractRole::CircleIntegration);
+    }
+}
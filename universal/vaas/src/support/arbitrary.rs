@@ -0,0 +1,556 @@
+//! [`proptest`] strategies for VAAs and every payload, so that integrators can fuzz their own
+//! handling of Wormhole messages with `any::<T>()`.
+#![cfg(feature = "proptest")]
+
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    collection, prop_oneof,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+use wormhole_io::{deploys::ChainId, WriteableSequence};
+
+use crate::{
+    payloads::{
+        gov::{self, GovernanceDecree, GovernanceMessage},
+        liquidity::{
+            CctpDeposit, DepositMessage, FastFill, FastMarketOrder, Fill, LiquidityLayerMessage,
+            SlowOrderResponse,
+        },
+        token_bridge::{Attestation, TokenBridgeMessage, Transfer, TransferWithMessage},
+        Message, PayloadKind,
+    },
+    utils::U256,
+    EncodedAmount, GuardianSetSig, Vaa, VaaBody, VaaHeader,
+};
+
+/// Longest payload generated for variable length fields without a length prefix.
+const MAX_PAYLOAD_LEN: usize = 512;
+
+/// Largest number of signatures generated for a VAA, the size of the current guardian set.
+const MAX_SIGNATURES: usize = 19;
+
+/// Implement [`Arbitrary`] for a struct with public fields, from one strategy per field.
+macro_rules! arbitrary_struct {
+    ($ty:ty { $($field:ident: $strategy:expr),+ $(,)? }) => {
+        impl Arbitrary for $ty {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                ($($strategy,)+)
+                    .prop_map(|($($field,)+)| Self { $($field),+ })
+                    .boxed()
+            }
+        }
+    };
+}
+
+/// Implement [`Arbitrary`] for an enum whose variants each wrap an [`Arbitrary`] type.
+macro_rules! arbitrary_enum {
+    ($ty:ty { $($variant:ident($inner:ty)),+ $(,)? }) => {
+        impl Arbitrary for $ty {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                prop_oneof![$(any::<$inner>().prop_map(Self::$variant)),+].boxed()
+            }
+        }
+    };
+}
+
+fn u256() -> impl Strategy<Value = U256> {
+    any::<[u8; 32]>().prop_map(U256::from_be_bytes)
+}
+
+fn payload() -> impl Strategy<Value = Vec<u8>> {
+    collection::vec(any::<u8>(), 0..=MAX_PAYLOAD_LEN)
+}
+
+fn sequence() -> impl Strategy<Value = WriteableSequence<u16, Vec<u8>>> {
+    payload().prop_map(Into::into)
+}
+
+impl Arbitrary for EncodedAmount {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        u256().prop_map(Self).boxed()
+    }
+}
+
+arbitrary_struct!(GuardianSetSig {
+    guardian_set_index: any::<u8>(),
+    signature: any::<[u8; 65]>(),
+});
+
+arbitrary_struct!(VaaHeader {
+    version: Just(1),
+    guardian_set_index: any::<u32>(),
+    signatures: collection::vec(any::<GuardianSetSig>(), 0..=MAX_SIGNATURES),
+});
+
+arbitrary_struct!(VaaBody {
+    timestamp: any::<u32>(),
+    nonce: any::<u32>(),
+    emitter_chain: any::<ChainId>(),
+    emitter_address: any::<[u8; 32]>(),
+    sequence: any::<u64>(),
+    consistency_level: any::<u8>(),
+    payload: any::<PayloadKind>(),
+});
+
+arbitrary_struct!(Vaa {
+    header: any::<VaaHeader>(),
+    body: any::<VaaBody>(),
+});
+
+impl Arbitrary for PayloadKind {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        payload().prop_map(Self::Binary).boxed()
+    }
+}
+
+arbitrary_struct!(Message {
+    version: any::<u8>(),
+    message_ty: any::<u8>(),
+    index: any::<u64>(),
+    target_chain: any::<ChainId>(),
+    target: sequence(),
+    sender: sequence(),
+    body: sequence(),
+});
+
+// Token bridge.
+
+arbitrary_struct!(Transfer {
+    norm_amount: any::<EncodedAmount>(),
+    token_address: any::<[u8; 32]>(),
+    token_chain: any::<ChainId>(),
+    recipient: any::<[u8; 32]>(),
+    recipient_chain: any::<ChainId>(),
+    norm_relayer_fee: any::<EncodedAmount>(),
+});
+
+arbitrary_struct!(Attestation {
+    token_address: any::<[u8; 32]>(),
+    token_chain: any::<ChainId>(),
+    decimals: any::<u8>(),
+    symbol: any::<[u8; 32]>(),
+    name: any::<[u8; 32]>(),
+});
+
+arbitrary_struct!(TransferWithMessage {
+    norm_amount: any::<EncodedAmount>(),
+    token_address: any::<[u8; 32]>(),
+    token_chain: any::<ChainId>(),
+    redeemer: any::<[u8; 32]>(),
+    redeemer_chain: any::<ChainId>(),
+    sender: any::<[u8; 32]>(),
+    payload: payload(),
+});
+
+arbitrary_enum!(TokenBridgeMessage {
+    Transfer(Transfer),
+    Attestation(Attestation),
+    TransferWithMessage(TransferWithMessage),
+});
+
+// Liquidity layer.
+
+arbitrary_struct!(CctpDeposit {
+    token_address: any::<[u8; 32]>(),
+    amount: any::<[u8; 32]>(),
+    source_cctp_domain: any::<u32>(),
+    destination_cctp_domain: any::<u32>(),
+    cctp_nonce: any::<u64>(),
+    burn_source: any::<[u8; 32]>(),
+    mint_recipient: any::<[u8; 32]>(),
+    payload: sequence(),
+});
+
+arbitrary_struct!(Fill {
+    source_chain: any::<ChainId>(),
+    order_sender: any::<[u8; 32]>(),
+    redeemer: any::<[u8; 32]>(),
+    redeemer_message: sequence(),
+});
+
+arbitrary_struct!(SlowOrderResponse {
+    base_fee: any::<u64>(),
+});
+
+arbitrary_struct!(FastFill {
+    fill_amount: any::<u64>(),
+    source_chain: any::<ChainId>(),
+    order_sender: any::<[u8; 32]>(),
+    redeemer: any::<[u8; 32]>(),
+    redeemer_message: sequence(),
+});
+
+arbitrary_struct!(FastMarketOrder {
+    amount_in: any::<u64>(),
+    min_amount_out: any::<u64>(),
+    target_chain: any::<ChainId>(),
+    redeemer: any::<[u8; 32]>(),
+    sender: any::<[u8; 32]>(),
+    refund_address: any::<[u8; 32]>(),
+    max_fee: any::<u64>(),
+    init_auction_fee: any::<u64>(),
+    deadline: any::<u32>(),
+    redeemer_message: sequence(),
+});
+
+arbitrary_enum!(LiquidityLayerMessage {
+    Deposit(CctpDeposit),
+    FastMarketOrder(FastMarketOrder),
+    FastFill(FastFill),
+});
+
+arbitrary_enum!(DepositMessage {
+    Fill(Fill),
+    SlowOrderResponse(SlowOrderResponse),
+});
+
+// Governance.
+
+impl<P: GovernanceDecree + Arbitrary + 'static> Arbitrary for GovernanceMessage<P> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        any::<P>().prop_map(Self::new).boxed()
+    }
+}
+
+arbitrary_struct!(gov::core_bridge::ContractUpgrade {
+    chain: any::<ChainId>(),
+    implementation: any::<[u8; 32]>(),
+});
+
+impl Arbitrary for gov::core_bridge::GuardianSetUpdate {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (
+            any::<u32>(),
+            // The raw parser rejects guardian set updates without guardians, see
+            // `test::empty_guardian_set`.
+            collection::vec(any::<[u8; 20]>(), 1..=u8::MAX as usize),
+        )
+            .prop_map(|(new_index, guardians)| Self::new(new_index, guardians))
+            .boxed()
+    }
+}
+
+arbitrary_struct!(gov::core_bridge::SetMessageFee {
+    chain: any::<ChainId>(),
+    fee: u256(),
+});
+
+arbitrary_struct!(gov::core_bridge::TransferFees {
+    chain: any::<ChainId>(),
+    amount: u256(),
+    recipient: any::<[u8; 32]>(),
+});
+
+arbitrary_struct!(gov::core_bridge::RecoverChainId {
+    recovered_chain: any::<ChainId>(),
+    evm_chain_id: u256(),
+    new_chain: any::<ChainId>(),
+});
+
+arbitrary_enum!(gov::core_bridge::Decree {
+    ContractUpgrade(gov::core_bridge::ContractUpgrade),
+    GuardianSetUpdate(gov::core_bridge::GuardianSetUpdate),
+    SetMessageFee(gov::core_bridge::SetMessageFee),
+    TransferFees(gov::core_bridge::TransferFees),
+    RecoverChainId(gov::core_bridge::RecoverChainId),
+});
+
+impl Arbitrary for gov::token_bridge::RegisterChain {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (any::<ChainId>(), any::<[u8; 32]>())
+            .prop_map(|(chain, emitter)| Self::new(chain, emitter))
+            .boxed()
+    }
+}
+
+arbitrary_struct!(gov::token_bridge::ContractUpgrade {
+    chain: any::<ChainId>(),
+    implementation: any::<[u8; 32]>(),
+});
+
+arbitrary_struct!(gov::token_bridge::RecoverChainId {
+    recovered_chain: any::<ChainId>(),
+    evm_chain_id: u256(),
+    new_chain: any::<ChainId>(),
+});
+
+arbitrary_enum!(gov::token_bridge::Decree {
+    RegisterChain(gov::token_bridge::RegisterChain),
+    ContractUpgrade(gov::token_bridge::ContractUpgrade),
+    RecoverChainId(gov::token_bridge::RecoverChainId),
+});
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use wormhole_raw_vaas as raw;
+
+    use super::*;
+    use crate::{Readable, TypePrefixedPayload, Writeable};
+
+    /// Encode the payload, check its size and that it decodes back to itself.
+    fn roundtrip<P: TypePrefixedPayload + PartialEq>(payload: &P) -> Vec<u8> {
+        let encoded = payload.to_payload_vec();
+        assert_eq!(payload.payload_written_size(), encoded.len());
+        assert_eq!(&P::read_slice(&encoded).unwrap(), payload);
+        encoded
+    }
+
+    proptest! {
+        #[test]
+        fn vaa(vaa: Vaa) {
+            let encoded = vaa.to_vec();
+            prop_assert_eq!(vaa.written_size(), encoded.len());
+            prop_assert_eq!(&Vaa::read(&mut encoded.as_slice()).unwrap(), &vaa);
+
+            let raw = raw::Vaa::parse(&encoded).unwrap();
+            prop_assert_eq!(raw.version(), vaa.header.version);
+            prop_assert_eq!(raw.guardian_set_index(), vaa.header.guardian_set_index);
+            prop_assert_eq!(raw.signature_count() as usize, vaa.header.signatures.len());
+            for (raw, owned) in raw.signatures().zip(&vaa.header.signatures) {
+                prop_assert_eq!(raw.guardian_index(), owned.guardian_set_index);
+                prop_assert_eq!(raw.signature(), owned.signature);
+            }
+
+            let body = raw.body();
+            prop_assert_eq!(body.timestamp(), vaa.body.timestamp);
+            prop_assert_eq!(body.nonce(), vaa.body.nonce);
+            prop_assert_eq!(vaa.body.emitter_chain, body.emitter_chain());
+            prop_assert_eq!(body.emitter_address(), vaa.body.emitter_address);
+            prop_assert_eq!(body.sequence(), vaa.body.sequence);
+            prop_assert_eq!(body.consistency_level(), vaa.body.consistency_level);
+            prop_assert_eq!(body.payload().as_ref().to_vec(), vaa.body.payload_bytes().unwrap());
+        }
+
+        #[test]
+        fn encoded_amount(amount: EncodedAmount) {
+            let encoded = amount.to_vec();
+            prop_assert_eq!(amount.written_size(), encoded.len());
+            prop_assert_eq!(EncodedAmount::read(&mut encoded.as_slice()).unwrap(), amount);
+        }
+
+        #[test]
+        fn message(message: Message) {
+            roundtrip(&message);
+        }
+
+        #[test]
+        fn token_bridge(message: TokenBridgeMessage) {
+            let encoded = roundtrip(&message);
+            let raw = raw::token_bridge::TokenBridgeMessage::parse(&encoded).unwrap();
+            match (&message, raw) {
+                (
+                    TokenBridgeMessage::Transfer(owned),
+                    raw::token_bridge::TokenBridgeMessage::Transfer(raw),
+                ) => {
+                    prop_assert_eq!(raw.amount(), owned.norm_amount.0.to_be_bytes());
+                    prop_assert_eq!(raw.token_address(), owned.token_address);
+                    prop_assert_eq!(owned.token_chain, raw.token_chain());
+                    prop_assert_eq!(raw.recipient(), owned.recipient);
+                    prop_assert_eq!(owned.recipient_chain, raw.recipient_chain());
+                    prop_assert_eq!(raw.relayer_fee(), owned.norm_relayer_fee.0.to_be_bytes());
+                }
+                (
+                    TokenBridgeMessage::Attestation(owned),
+                    raw::token_bridge::TokenBridgeMessage::Attestation(raw),
+                ) => {
+                    prop_assert_eq!(raw.token_address(), owned.token_address);
+                    prop_assert_eq!(owned.token_chain, raw.token_chain());
+                    prop_assert_eq!(raw.decimals(), owned.decimals);
+                    prop_assert_eq!(raw.symbol(), owned.symbol_string());
+                    prop_assert_eq!(raw.name(), owned.name_string());
+                }
+                (
+                    TokenBridgeMessage::TransferWithMessage(owned),
+                    raw::token_bridge::TokenBridgeMessage::TransferWithMessage(raw),
+                ) => {
+                    prop_assert_eq!(raw.amount(), owned.norm_amount.0.to_be_bytes());
+                    prop_assert_eq!(raw.token_address(), owned.token_address);
+                    prop_assert_eq!(owned.token_chain, raw.token_chain());
+                    prop_assert_eq!(raw.redeemer(), owned.redeemer);
+                    prop_assert_eq!(owned.redeemer_chain, raw.redeemer_chain());
+                    prop_assert_eq!(raw.sender(), owned.sender);
+                    prop_assert_eq!(raw.payload().as_ref().to_vec(), owned.payload.as_slice());
+                }
+                _ => prop_assert!(false, "mismatched token bridge message"),
+            }
+        }
+
+        #[test]
+        fn liquidity_layer(message: LiquidityLayerMessage) {
+            let encoded = roundtrip(&message);
+            let raw = raw::liquidity::LiquidityLayerMessage::parse(&encoded).unwrap();
+            match (&message, raw) {
+                (
+                    LiquidityLayerMessage::Deposit(owned),
+                    raw::liquidity::LiquidityLayerMessage::Deposit(raw),
+                ) => {
+                    prop_assert_eq!(raw.token_address(), owned.token_address);
+                    prop_assert_eq!(raw.amount(), owned.amount);
+                    prop_assert_eq!(raw.source_cctp_domain(), owned.source_cctp_domain);
+                    prop_assert_eq!(raw.destination_cctp_domain(), owned.destination_cctp_domain);
+                    prop_assert_eq!(raw.cctp_nonce(), owned.cctp_nonce);
+                    prop_assert_eq!(raw.burn_source(), owned.burn_source);
+                    prop_assert_eq!(raw.mint_recipient(), owned.mint_recipient);
+                    prop_assert_eq!(raw.payload().as_ref().to_vec(), owned.payload.as_slice());
+                }
+                (
+                    LiquidityLayerMessage::FastMarketOrder(owned),
+                    raw::liquidity::LiquidityLayerMessage::FastMarketOrder(raw),
+                ) => {
+                    prop_assert_eq!(raw.amount_in(), owned.amount_in);
+                    prop_assert_eq!(raw.min_amount_out(), owned.min_amount_out);
+                    prop_assert_eq!(owned.target_chain, raw.target_chain());
+                    prop_assert_eq!(raw.redeemer(), owned.redeemer);
+                    prop_assert_eq!(raw.sender(), owned.sender);
+                    prop_assert_eq!(raw.refund_address(), owned.refund_address);
+                    prop_assert_eq!(raw.max_fee(), owned.max_fee);
+                    prop_assert_eq!(raw.init_auction_fee(), owned.init_auction_fee);
+                    prop_assert_eq!(raw.deadline(), owned.deadline);
+                    prop_assert_eq!(raw.redeemer_message().as_ref().to_vec(), owned.redeemer_message.as_slice());
+                }
+                (
+                    LiquidityLayerMessage::FastFill(owned),
+                    raw::liquidity::LiquidityLayerMessage::FastFill(raw),
+                ) => {
+                    prop_assert_eq!(raw.fill_amount(), owned.fill_amount);
+                    prop_assert_eq!(owned.source_chain, raw.source_chain());
+                    prop_assert_eq!(raw.order_sender(), owned.order_sender);
+                    prop_assert_eq!(raw.redeemer(), owned.redeemer);
+                    prop_assert_eq!(raw.redeemer_message().as_ref().to_vec(), owned.redeemer_message.as_slice());
+                }
+                _ => prop_assert!(false, "mismatched liquidity layer message"),
+            }
+        }
+
+        #[test]
+        fn deposit_message(message: DepositMessage) {
+            let encoded = roundtrip(&message);
+            let raw = raw::liquidity::LiquidityLayerDepositMessage::parse(&encoded).unwrap();
+            match (&message, raw) {
+                (
+                    DepositMessage::Fill(owned),
+                    raw::liquidity::LiquidityLayerDepositMessage::Fill(raw),
+                ) => {
+                    prop_assert_eq!(owned.source_chain, raw.source_chain());
+                    prop_assert_eq!(raw.order_sender(), owned.order_sender);
+                    prop_assert_eq!(raw.redeemer(), owned.redeemer);
+                    prop_assert_eq!(raw.redeemer_message().as_ref().to_vec(), owned.redeemer_message.as_slice());
+                }
+                (
+                    DepositMessage::SlowOrderResponse(owned),
+                    raw::liquidity::LiquidityLayerDepositMessage::SlowOrderResponse(raw),
+                ) => prop_assert_eq!(raw.base_fee(), owned.base_fee),
+                _ => prop_assert!(false, "mismatched deposit message"),
+            }
+        }
+
+        #[test]
+        fn core_governance(message: GovernanceMessage<gov::core_bridge::Decree>) {
+            use gov::core_bridge::Decree;
+            use raw::core::CoreBridgeDecree;
+
+            let encoded = roundtrip(&message);
+            let raw = raw::core::CoreBridgeGovPayload::parse(&encoded).unwrap();
+            match (&message.decree, raw.decree()) {
+                (Decree::ContractUpgrade(owned), CoreBridgeDecree::ContractUpgrade(raw)) => {
+                    prop_assert_eq!(owned.chain, raw.chain());
+                    prop_assert_eq!(raw.implementation(), owned.implementation);
+                }
+                (Decree::GuardianSetUpdate(owned), CoreBridgeDecree::GuardianSetUpdate(raw)) => {
+                    prop_assert_eq!(raw.new_index(), owned.new_index);
+                    prop_assert_eq!(raw.num_guardians() as usize, owned.guardians.len());
+                    for (i, guardian) in owned.guardians.iter().enumerate() {
+                        prop_assert_eq!(&raw.guardian_at(i), guardian);
+                    }
+                }
+                (Decree::SetMessageFee(owned), CoreBridgeDecree::SetMessageFee(raw)) => {
+                    prop_assert_eq!(owned.chain, raw.chain());
+                    prop_assert_eq!(raw.fee(), owned.fee.to_be_bytes());
+                }
+                (Decree::TransferFees(owned), CoreBridgeDecree::TransferFees(raw)) => {
+                    prop_assert_eq!(owned.chain, raw.chain());
+                    prop_assert_eq!(raw.amount(), owned.amount.to_be_bytes());
+                    prop_assert_eq!(raw.recipient(), owned.recipient);
+                }
+                (Decree::RecoverChainId(owned), CoreBridgeDecree::RecoverChainId(raw)) => {
+                    prop_assert_eq!(owned.recovered_chain, raw.recovered_chain());
+                    prop_assert_eq!(raw.evm_chain_id(), owned.evm_chain_id.to_be_bytes());
+                    prop_assert_eq!(owned.new_chain, raw.new_chain());
+                }
+                _ => prop_assert!(false, "mismatched core bridge decree"),
+            }
+        }
+
+        #[test]
+        fn token_bridge_governance(message: GovernanceMessage<gov::token_bridge::Decree>) {
+            use gov::token_bridge::Decree;
+            use raw::token_bridge::TokenBridgeDecree;
+
+            let encoded = roundtrip(&message);
+            let raw = raw::token_bridge::TokenBridgeGovPayload::parse(&encoded).unwrap();
+            match (&message.decree, raw.decree()) {
+                (Decree::RegisterChain(owned), TokenBridgeDecree::RegisterChain(raw)) => {
+                    prop_assert_eq!(owned.foreign_chain, raw.foreign_chain());
+                    prop_assert_eq!(raw.foreign_emitter(), owned.foreign_emitter);
+                }
+                (Decree::ContractUpgrade(owned), TokenBridgeDecree::ContractUpgrade(raw)) => {
+                    prop_assert_eq!(owned.chain, raw.chain());
+                    prop_assert_eq!(raw.implementation(), owned.implementation);
+                }
+                (Decree::RecoverChainId(owned), TokenBridgeDecree::RecoverChainId(raw)) => {
+                    prop_assert_eq!(owned.recovered_chain, raw.recovered_chain());
+                    prop_assert_eq!(raw.evm_chain_id(), owned.evm_chain_id.to_be_bytes());
+                    prop_assert_eq!(owned.new_chain, raw.new_chain());
+                }
+                _ => prop_assert!(false, "mismatched token bridge decree"),
+            }
+        }
+    }
+
+    #[test]
+    fn empty_guardian_set() {
+        use gov::core_bridge::{Decree, GuardianSetUpdate};
+
+        // The owned types leave the number of guardians to the caller, but the raw parser
+        // insists on at least one.
+        let message = GovernanceMessage::new(Decree::GuardianSetUpdate(GuardianSetUpdate::new(
+            1,
+            Vec::new(),
+        )));
+        let encoded = roundtrip(&message);
+        assert_eq!(
+            raw::core::CoreBridgeGovPayload::parse(&encoded).err(),
+            Some(
+                "GuardianSetUpdate span too short. Need at least 27 bytes (for at least 1 guardian)"
+            )
+        );
+
+        let message = GovernanceMessage::new(Decree::GuardianSetUpdate(GuardianSetUpdate::new(
+            1,
+            vec![[1; 20]],
+        )));
+        assert!(raw::core::CoreBridgeGovPayload::parse(&roundtrip(&message)).is_ok());
+    }
+}
@@ -0,0 +1,259 @@
+//! Rendering of VAAs in the shape of the Wormhole JS CLI `worm parse` output.
+#![cfg(feature = "serde")]
+
+use serde_json::{json, Value};
+
+use crate::{
+    payloads::{
+        gov::{self, GovernanceMessage},
+        liquidity::LiquidityLayerMessage,
+        token_bridge::TokenBridgeMessage,
+        KnownPayload,
+    },
+    Vaa,
+};
+
+impl Vaa {
+    /// Render the VAA as JSON with its payload decoded by [`KnownPayload::decode`].
+    ///
+    /// The output matches the shape of the Wormhole JS CLI `parse` command: big integers are
+    /// decimal strings, addresses are `0x`-prefixed hex and chains are numeric. Token bridge and
+    /// governance payloads use the CLI's `module`/`type` field names. Payloads the CLI does not
+    /// know are rendered with their serde representation under a `type` field, and payloads
+    /// which do not decode at all as `{ "type": "Other", "hex": ... }`.
+    pub fn to_json_decoded(&self) -> Value {
+        let mut out = serde_json::to_value(self).expect("VAA serializes to JSON");
+
+        if let Some(bytes) = self.body.payload_bytes() {
+            out["payload"] = match self.body.known_payload() {
+                KnownPayload::Unknown(_) => other(bytes),
+                known => render(known),
+            };
+        }
+        out["digest"] = Value::String(format!("0x{}", self.body.double_digest()));
+        out
+    }
+}
+
+fn render(payload: KnownPayload) -> Value {
+    match payload {
+        KnownPayload::CoreGovernance(GovernanceMessage { decree, .. }) => core_decree(decree),
+        KnownPayload::TokenBridgeGovernance(GovernanceMessage { decree, .. }) => {
+            token_bridge_decree(decree)
+        }
+        KnownPayload::TokenBridge(message) => token_bridge(message),
+        KnownPayload::Cctp(inner) => tagged("CctpDeposit", &inner),
+        KnownPayload::LiquidityLayer(message) => liquidity_layer(message),
+        KnownPayload::Message(inner) => tagged("Message", &inner),
+        KnownPayload::Unknown(bytes) => other(&bytes),
+    }
+}
+
+fn core_decree(decree: gov::core_bridge::Decree) -> Value {
+    use gov::core_bridge::Decree;
+
+    const MODULE: &str = "Core";
+    match decree {
+        Decree::ContractUpgrade(inner) => json!({
+            "module": MODULE,
+            "type": "ContractUpgrade",
+            "chain": inner.chain.to_u16(),
+            "address": hex(&inner.implementation),
+        }),
+        Decree::GuardianSetUpdate(inner) => json!({
+            "module": MODULE,
+            "type": "GuardianSetUpgrade",
+            "chain": 0,
+            "newGuardianSetIndex": inner.new_index,
+            "newGuardianSetLength": inner.guardians.len(),
+            "newGuardianSet": inner
+                .guardians
+                .iter()
+                .map(|key| hex(key)[2..].to_string())
+                .collect::<Vec<_>>(),
+        }),
+        Decree::SetMessageFee(inner) => json!({
+            "module": MODULE,
+            "type": "SetMessageFee",
+            "chain": inner.chain.to_u16(),
+            "fee": inner.fee.to_string(),
+        }),
+        Decree::TransferFees(inner) => json!({
+            "module": MODULE,
+            "type": "TransferFees",
+            "chain": inner.chain.to_u16(),
+            "amount": inner.amount.to_string(),
+            "recipient": hex(&inner.recipient),
+        }),
+        Decree::RecoverChainId(inner) => json!({
+            "module": MODULE,
+            "type": "RecoverChainId",
+            "evmChainId": inner.evm_chain_id.to_string(),
+            "newChainId": inner.new_chain.to_u16(),
+        }),
+    }
+}
+
+fn token_bridge_decree(decree: gov::token_bridge::Decree) -> Value {
+    use gov::token_bridge::Decree;
+
+    const MODULE: &str = "TokenBridge";
+    match decree {
+        Decree::RegisterChain(inner) => json!({
+            "module": MODULE,
+            "type": "RegisterChain",
+            "chain": 0,
+            "emitterChain": inner.foreign_chain.to_u16(),
+            "emitterAddress": hex(&inner.foreign_emitter),
+        }),
+        Decree::ContractUpgrade(inner) => json!({
+            "module": MODULE,
+            "type": "ContractUpgrade",
+            "chain": inner.chain.to_u16(),
+            "address": hex(&inner.implementation),
+        }),
+        Decree::RecoverChainId(inner) => json!({
+            "module": MODULE,
+            "type": "RecoverChainId",
+            "evmChainId": inner.evm_chain_id.to_string(),
+            "newChainId": inner.new_chain.to_u16(),
+        }),
+    }
+}
+
+fn token_bridge(message: TokenBridgeMessage) -> Value {
+    const MODULE: &str = "TokenBridge";
+    match message {
+        TokenBridgeMessage::Transfer(inner) => json!({
+            "module": MODULE,
+            "type": "Transfer",
+            "amount": inner.norm_amount.0.to_string(),
+            "tokenAddress": hex(&inner.token_address),
+            "tokenChain": inner.token_chain.to_u16(),
+            "toAddress": hex(&inner.recipient),
+            "chain": inner.recipient_chain.to_u16(),
+            "fee": inner.norm_relayer_fee.0.to_string(),
+        }),
+        TokenBridgeMessage::Attestation(inner) => json!({
+            "module": MODULE,
+            "type": "AttestMeta",
+            "chain": 0,
+            "tokenAddress": hex(&inner.token_address),
+            "tokenChain": inner.token_chain.to_u16(),
+            "decimals": inner.decimals,
+            "symbol": inner.symbol_string(),
+            "name": inner.name_string(),
+        }),
+        TokenBridgeMessage::TransferWithMessage(inner) => json!({
+            "module": MODULE,
+            "type": "TransferWithPayload",
+            "amount": inner.norm_amount.0.to_string(),
+            "tokenAddress": hex(&inner.token_address),
+            "tokenChain": inner.token_chain.to_u16(),
+            "toAddress": hex(&inner.redeemer),
+            "chain": inner.redeemer_chain.to_u16(),
+            "fromAddress": hex(&inner.sender),
+            "payload": hex(&inner.payload),
+        }),
+    }
+}
+
+fn liquidity_layer(message: LiquidityLayerMessage) -> Value {
+    match message {
+        LiquidityLayerMessage::Deposit(inner) => tagged("CctpDeposit", &inner),
+        LiquidityLayerMessage::FastMarketOrder(inner) => tagged("FastMarketOrder", &inner),
+        LiquidityLayerMessage::FastFill(inner) => tagged("FastFill", &inner),
+    }
+}
+
+fn tagged<T: serde::Serialize>(ty: &str, inner: &T) -> Value {
+    let mut value = serde_json::to_value(inner).expect("payload serializes to JSON");
+    if let Value::Object(map) = &mut value {
+        map.insert("type".into(), ty.into());
+    }
+    value
+}
+
+fn other(bytes: &[u8]) -> Value {
+    let mut value = json!({
+        "type": "Other",
+        "hex": hex(bytes)[2..],
+    });
+    if bytes.iter().all(|b| b.is_ascii_graphic() || *b == b' ') {
+        value["ascii"] = String::from_utf8_lossy(bytes).into();
+    }
+    value
+}
+
+fn hex(bytes: &[u8]) -> String {
+    super::serde::to_hex(bytes, true)
+}
+
+#[cfg(test)]
+mod test {
+    use hex_literal::hex;
+    use serde_json::json;
+
+    use crate::{Readable, Vaa};
+
+    // https://github.com/wormhole-foundation/wormhole/blob/b09a644dac97fa8e037a16765728217ff3a1d057/clients/js/parse_tests/token-bridge-transfer-2.expected
+    const TRANSFER: [u8; 1048] = hex!("01000000010d0078588270e30e3b4cf74572b6ad4270cdd7932079692170fddaf369c7574722b75defcecf5d372cdd8fdba0f275c6b902434259b5d7da8402e25ca852ca5affaa0003a8888cf66158970861329efa69ff2461d847078cec22fd7f62606b17a1ae283127712fa50dc365faa1e6db339fefce57b13c74c2dce7d14b79051676c74bb685000487272398eb59763bb1e2466f9ebdea4e75c290b6c0386f07c20e1296b1976cb814547378922dbc5490b7fcf7279eafc0c08bd59ca97c4dbbcbd478967e17aa2d0006dd38ecb6233f1cd872a75cc0627ded36aa8f89095436f7dbe32e6655e27f217459fda35a3d7f1d656962160bfeee4e5fc6d2e1447559e7bc3ba760416317b86c010792d27a749b398dc5f085e7bcd2e0f18d6262a1ba1916787ec01854c0ccde0a8247f8892e6dff83fad6839fc054f32734255e9037ff9adc33499514e2300ba439010989f08688ae363783bfe3f25a5960a0791ce327bab7e7593393f91395e06fe50e3f7e13862ac86b9fd1f9720669bc4504e918f7e481c395f17a2fa131da05b9e7010a097d187970710297d188a2ebaedff0ad13efd16872566bae8a56377e28466b2c3c4e47853c60fe716109e55f8b453fb03a34bb1929c96f74ebd796a476ec7ab6000b68a19d198350b3caebd3c0159b8bbce022e0f026d013a1c83e40d6100c87e8bb0d692baca89cb77f4b6832dd7aaf3f2f7c482fd50be7221c046ae668228ec013000cd6f464a174d7e34797e2869785feb5f05ab614be989d238c9bd55259dbdbab2568c14f316d1820ac766e513bf5225185f16d30f0f01a092af5fb6b072ad577f0010d663f2f3ad62baa8ad541b9c38bb9df805d2cfa7072894526505b654293bacdee5e9e8c4ded7be92a3338b964482b3ce6d5275817d6a4b6a0663e1e84dcd1de3500105f773ea1d7e74770e78c4779abe4594b6a46f9131304948265bc185dcb1cdba8114915e3b1d864f48e4c694c9578524e22752e2d898af4b8e67383d72a11856700118bdbd5b5a820ecd215faf134b698402da04cc698e64464dd8df6692342e8c44314e1ae53bfde71fb2b00cd5691dae4f9b310c6150bdb551645a72863f4ff965c011286c673c4f2213969d273b939318f93a5b50c665efa8c9e245a3b8823522dafec209b1be127e74a6d5c924831e339f8bffb769f7b0f5772ed16231700bf7eece200624092e10000f4150001ec7372995d5cc8732397fb0ad35c0121e0eaa90d26f828a534cab54391b3a4f5000000000001aec5200100000000000000000000000000000000000000000000000000000000f4610900069b8857feab8184fb687f634618c035dac439dc1aeb3b5598a0f000000000010001000000000000000000000000efd4aa8f954ebdea82b8757c029fc8475a45e9cd00020000000000000000000000000000000000000000000000000000000000000000");
+
+    #[test]
+    fn token_bridge_transfer() {
+        let vaa = Vaa::read(&mut TRANSFER.as_slice()).unwrap();
+        let json = vaa.to_json_decoded();
+
+        assert_eq!(json["version"], 1);
+        assert_eq!(json["guardianSetIndex"], 1);
+        assert_eq!(
+            json["signatures"][0],
+            json!({
+                "guardianSetIndex": 0,
+                "signature": "78588270e30e3b4cf74572b6ad4270cdd7932079692170fddaf369c7574722b75defcecf5d372cdd8fdba0f275c6b902434259b5d7da8402e25ca852ca5affaa00",
+            })
+        );
+        assert_eq!(json["timestamp"], 1648399073);
+        assert_eq!(json["nonce"], 62485);
+        assert_eq!(json["emitterChain"], 1);
+        assert_eq!(
+            json["emitterAddress"],
+            "0xec7372995d5cc8732397fb0ad35c0121e0eaa90d26f828a534cab54391b3a4f5"
+        );
+        assert_eq!(json["sequence"], "110277");
+        assert_eq!(json["consistencyLevel"], 32);
+        assert_eq!(
+            json["payload"],
+            json!({
+                "module": "TokenBridge",
+                "type": "Transfer",
+                "amount": "4100000000",
+                "tokenAddress": "0x069b8857feab8184fb687f634618c035dac439dc1aeb3b5598a0f00000000001",
+                "tokenChain": 1,
+                "toAddress": "0x000000000000000000000000efd4aa8f954ebdea82b8757c029fc8475a45e9cd",
+                "chain": 2,
+                "fee": "0",
+            })
+        );
+        assert_eq!(
+            json["digest"],
+            "0xc90519b2bdfacac401d2d2c15a329d4e33e8ca15862685f0220ddc6074d7def5"
+        );
+    }
+
+    #[test]
+    fn other() {
+        let mut vaa = Vaa::read(&mut TRANSFER.as_slice()).unwrap();
+        vaa.body.emitter_address = [0; 32];
+        vaa.body.payload = crate::payloads::PayloadKind::Binary(b"hello world".to_vec());
+
+        assert_eq!(
+            vaa.to_json_decoded()["payload"],
+            json!({
+                "type": "Other",
+                "hex": "68656c6c6f20776f726c64",
+                "ascii": "hello world",
+            })
+        );
+    }
+}
@@ -1 +1,4 @@
+mod arbitrary;
+pub(crate) mod hex;
+mod js;
 pub(crate) mod serde;
@@ -1,13 +1,27 @@
 #![cfg(feature = "serde")]
-use serde::{de, de::SeqAccess, Deserializer, Serialize, Serializer};
+use serde::{de, de::SeqAccess, Deserializer, Serializer};
 
-pub(crate) mod fixed_bytes_as_array {
+/// Fixed length byte arrays as `0x`-prefixed hex strings. Deserialization also accepts
+/// unprefixed hex or an array of numbers.
+pub(crate) mod hex_array {
     use super::*;
+
     pub fn serialize<S, const N: usize>(bytes: &[u8; N], serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        bytes.to_vec().serialize(serializer)
+        serializer.serialize_str(&to_hex(bytes, true))
+    }
+
+    /// Serializes without the `0x` prefix.
+    pub fn serialize_unprefixed<S, const N: usize>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&to_hex(bytes, false))
     }
 
     pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
@@ -52,14 +66,162 @@ pub(crate) mod fixed_bytes_as_array {
             where
                 E: de::Error,
             {
-                s.parse()
-                    .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(s), &"a hex string"))
+                crate::support::hex::decode_array(s).ok_or_else(|| {
+                    de::Error::invalid_value(de::Unexpected::Str(s), &"a hex string")
+                })
             }
         }
         deserializer.deserialize_any(FbVisitor::<N>)
     }
 }
 
+/// Writes bytes as lowercase hex, with or without a `0x` prefix.
+pub(super) fn to_hex(bytes: &[u8], prefixed: bool) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::with_capacity(2 + bytes.len() * 2);
+    if prefixed {
+        out.push_str("0x");
+    }
+    for byte in bytes {
+        write!(out, "{byte:02x}").unwrap();
+    }
+    out
+}
+
+fn from_hex<E: de::Error>(s: &str) -> Result<Vec<u8>, E> {
+    let digits = s.strip_prefix("0x").unwrap_or(s);
+    if digits.len() % 2 != 0 {
+        return Err(E::invalid_value(de::Unexpected::Str(s), &"a hex string"));
+    }
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(digits.get(i..i + 2).unwrap_or("-"), 16))
+        .collect::<Result<_, _>>()
+        .map_err(|_| E::invalid_value(de::Unexpected::Str(s), &"a hex string"))
+}
+
+/// A sequence of fixed length byte arrays, as a list of `0x`-prefixed hex strings.
+pub(crate) mod hex_array_seq {
+    use super::*;
+    use wormhole_io::WriteableSequence;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(transparent)]
+    struct Hex<const N: usize>(#[serde(with = "super::hex_array")] [u8; N]);
+
+    pub fn serialize<S, L, const N: usize>(
+        seq: &WriteableSequence<L, Vec<[u8; N]>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        usize: TryInto<L>,
+    {
+        serializer.collect_seq(seq.iter().map(|bytes| Hex(*bytes)))
+    }
+
+    pub fn deserialize<'de, D, L, const N: usize>(
+        deserializer: D,
+    ) -> Result<WriteableSequence<L, Vec<[u8; N]>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        usize: TryInto<L>,
+    {
+        let seq = <Vec<Hex<N>> as serde::Deserialize>::deserialize(deserializer)?;
+        let seq: Vec<[u8; N]> = seq.into_iter().map(|Hex(bytes)| bytes).collect();
+        Ok(WriteableSequence::<L, Vec<[u8; N]>>::new(seq))
+    }
+}
+
+/// Variable length bytes (`Vec<u8>` or a [`WriteableSequence`] of them) as `0x`-prefixed hex.
+///
+/// [`WriteableSequence`]: wormhole_io::WriteableSequence
+pub(crate) mod hex_bytes {
+    use super::*;
+    use std::ops::Deref;
+
+    pub fn serialize<S, T>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Deref,
+        T::Target: AsRef<[u8]>,
+    {
+        serializer.serialize_str(&to_hex(bytes.deref().as_ref(), true))
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: From<Vec<u8>>,
+    {
+        let s = <std::borrow::Cow<'de, str> as serde::Deserialize>::deserialize(deserializer)?;
+        from_hex(&s).map(Into::into)
+    }
+}
+
+/// Integers as decimal strings, as JSON numbers cannot hold them exactly. Deserialization also
+/// accepts numbers.
+pub(crate) mod decimal {
+    use super::*;
+    use std::{
+        fmt::{self, Display},
+        marker::PhantomData,
+        str::FromStr,
+    };
+
+    pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Display,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr,
+    {
+        struct Decimal<T>(PhantomData<T>);
+
+        impl<T: FromStr> de::Visitor<'_> for Decimal<T> {
+            type Value = T;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a decimal string or number")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+                if s.starts_with("0x") {
+                    return Err(E::invalid_value(de::Unexpected::Str(s), &self));
+                }
+                s.parse()
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(s), &self))
+            }
+
+            fn visit_u64<E: de::Error>(self, n: u64) -> Result<T, E> {
+                n.to_string()
+                    .parse()
+                    .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(n), &self))
+            }
+
+            fn visit_u128<E: de::Error>(self, n: u128) -> Result<T, E> {
+                n.to_string()
+                    .parse()
+                    .map_err(|_| E::custom(format_args!("invalid integer {n}")))
+            }
+        }
+
+        // Only self-describing formats can tell strings and numbers apart.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(Decimal(PhantomData))
+        } else {
+            deserializer.deserialize_str(Decimal(PhantomData))
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::payloads::PayloadKind;
@@ -89,4 +251,15 @@ mod test {
         assert_eq!(vaa.header.version, 1);
         assert_eq!(vaa.body.payload, PayloadKind::Binary(vec![0, 1, 2]))
     }
+
+    #[test]
+    fn decimal() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Sequence(#[serde(with = "super::decimal")] u64);
+
+        assert_eq!(serde_json::from_str("\"3\"").ok(), Some(Sequence(3)));
+        assert_eq!(serde_json::from_str("3").ok(), Some(Sequence(3)));
+        assert!(serde_json::from_str::<Sequence>("\"0x3\"").is_err());
+        assert!(serde_json::from_str::<Sequence>("-3").is_err());
+    }
 }